@@ -0,0 +1,16 @@
+//! A client for the Box API, used by the `bx` CLI.
+//!
+//! ```no_run
+//! # async fn example() -> Result<(), bx::sdk::SDKError> {
+//! let mut client = bx::sdk::Client::new("<BOX_DEV_TOKEN>".to_owned());
+//! let file = client.file("12345").get().await?;
+//! println!("{:?}", file.name());
+//! # Ok(())
+//! # }
+//! ```
+
+#![warn(rust_2018_idioms)]
+#![deny(clippy::all)]
+#![allow(clippy::upper_case_acronyms)]
+
+pub mod sdk;
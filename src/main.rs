@@ -1,21 +1,43 @@
 #![warn(rust_2018_idioms)]
 #![deny(clippy::all)]
+#![allow(clippy::upper_case_acronyms)]
 
-mod sdk;
-
-use clap::{App, AppSettings, Arg, ArgGroup, SubCommand};
-use sdk::operations::FileUpdates;
-use sdk::Client;
-use sdk::SDKError;
+use bx::sdk::models::{
+    Comment, FileCollection, Item, Metadata, MetadataField, MetadataFieldType, MetadataTemplate,
+    ResolutionState, SharedLinkAccess, TaskAction,
+};
+use bx::sdk::operations::{
+    Destination, DownloadPermission, ExistingFilePolicy, FileUpdates, FolderListings,
+    MetadataOperation, MetadataPatch, MetadataQuery, MetadataTemplateChange,
+    MetadataTemplateUpdates, MetadataUpdates, NewMetadataTemplate, NewTask, NewWebLink,
+    NewZipDownload, SharedLinkUpdates, SortDirection, UserQuery, UserType, WebLinkUpdates,
+};
+use bx::sdk::Client;
+use bx::sdk::SDKError;
+use chrono::{DateTime, Duration, FixedOffset, Utc};
+use clap::{App, AppSettings, Arg, ArgGroup, ArgMatches, SubCommand};
+use futures::{stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
 use serde::Serialize;
+use serde_json::{Map, Value};
 use std::fmt::Debug;
 use std::path::Path;
+use std::slice;
 
 enum OutputFormat {
     Debug,
     JSON,
 }
 
+enum DownloadMode {
+    Whole,
+    Range(u64, Option<u64>),
+    Parallel,
+}
+
+/// One of the changes asked for by `bx metadata-template update`.
+type TemplateEdit<'a> = Box<dyn FnOnce(MetadataTemplateUpdates) -> MetadataTemplateUpdates + 'a>;
+
 struct Context {
     client: Client,
     fmt: OutputFormat,
@@ -31,10 +53,124 @@ impl Context {
             OutputFormat::JSON => println!("{}", &serde_json::to_string(&object).unwrap()),
         }
     }
+
+    /// Metadata is shown as a flat table with a row per field, unless JSON
+    /// output was asked for.
+    pub(crate) fn output_metadata<T>(&self, object: T, instances: &[Metadata])
+    where
+        T: Serialize + Debug,
+    {
+        match self.fmt {
+            OutputFormat::Debug => print_metadata_table(instances),
+            OutputFormat::JSON => self.output(object),
+        }
+    }
+}
+
+enum MetadataTarget<'a> {
+    File(&'a str),
+    Folder(&'a str),
+}
+
+impl<'a> MetadataTarget<'a> {
+    fn operation<'b>(&'b self, client: &'b mut Client) -> MetadataOperation<'b> {
+        match *self {
+            MetadataTarget::File(id) => client.file_metadata(id),
+            MetadataTarget::Folder(id) => client.folder_metadata(id),
+        }
+    }
+}
+
+fn chunked_upload_args<'a, 'b>() -> [Arg<'a, 'b>; 2] {
+    [
+        Arg::with_name("chunkedThreshold")
+            .help("Upload files larger than this many bytes in parallel chunks")
+            .long("chunked-threshold")
+            .default_value("52428800"),
+        Arg::with_name("concurrency")
+            .help("The number of chunks to upload at the same time")
+            .long("concurrency")
+            .default_value("4"),
+    ]
+}
+
+fn metadata_subcommand<'a, 'b>() -> App<'a, 'b> {
+    let template_arg = || {
+        Arg::with_name("template")
+            .help("The key of the metadata template")
+            .required(true)
+    };
+    let scope_arg = || {
+        Arg::with_name("scope")
+            .help("The scope of the metadata template")
+            .long("scope")
+            .default_value("enterprise")
+    };
+    let fields_arg = || {
+        Arg::with_name("fields")
+            .help("Field values as FIELD=VALUE, or FIELD:=JSON for numbers and lists")
+            .multiple(true)
+    };
+
+    SubCommand::with_name("metadata")
+        .about("List the metadata on the item")
+        .subcommand(
+            SubCommand::with_name("get")
+                .about("Display one metadata instance")
+                .arg(template_arg())
+                .arg(scope_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("create")
+                .about("Apply a metadata template to the item")
+                .arg(template_arg())
+                .arg(fields_arg())
+                .arg(
+                    Arg::with_name("data")
+                        .help("A JSON file with an object of field values")
+                        .long("data")
+                        .takes_value(true),
+                )
+                .arg(scope_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("update")
+                .about("Change the values of a metadata instance")
+                .arg(template_arg())
+                .arg(fields_arg())
+                .arg(
+                    Arg::with_name("remove")
+                        .help("A field to remove the value of")
+                        .long("remove")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name("data")
+                        .help("A JSON file with an array of JSON-Patch operations")
+                        .long("data")
+                        .takes_value(true),
+                )
+                .arg(scope_arg()),
+        )
+        .subcommand(
+            SubCommand::with_name("delete")
+                .about("Remove a metadata template from the item")
+                .arg(template_arg())
+                .arg(scope_arg()),
+        )
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
+    if let Err(error) = run().await {
+        eprintln!("Error: {}", error);
+        std::process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn std::error::Error>> {
     let app = App::new(env!("CARGO_PKG_NAME"))
         .version(env!("CARGO_PKG_VERSION"))
         .author(env!("CARGO_PKG_AUTHORS"))
@@ -69,7 +205,86 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .subcommand(SubCommand::with_name("delete"))
                 .subcommand(
                     SubCommand::with_name("download")
-                        .arg(Arg::with_name("path").default_value(".")),
+                        .arg(Arg::with_name("path").default_value("."))
+                        .arg(
+                            Arg::with_name("range")
+                                .long("range")
+                                .takes_value(true)
+                                .value_name("START-END")
+                                .help("Only download the given bytes of the file, e.g. 0-1023 or 1024-"),
+                        )
+                        .arg(
+                            Arg::with_name("parallel")
+                                .long("parallel")
+                                .conflicts_with("range")
+                                .help("Download ranges of the file over several connections at once"),
+                        )
+                        .arg(
+                            Arg::with_name("partSize")
+                                .long("part-size")
+                                .default_value("16777216")
+                                .help("The number of bytes each connection fetches at a time"),
+                        )
+                        .arg(
+                            Arg::with_name("concurrency")
+                                .long("concurrency")
+                                .default_value("4")
+                                .help("The number of connections to download over"),
+                        )
+                        .arg(
+                            Arg::with_name("overwrite")
+                                .long("overwrite")
+                                .help("Replace the local file if it already exists"),
+                        )
+                        .arg(
+                            Arg::with_name("skipExisting")
+                                .long("skip-existing")
+                                .conflicts_with("overwrite")
+                                .help("Keep the local file if it already exists"),
+                        )
+                        .arg(
+                            Arg::with_name("rename")
+                                .long("rename")
+                                .conflicts_with_all(&["overwrite", "skipExisting"])
+                                .help("Save next to the local file as \"name (1)\" if it already exists"),
+                        )
+                        .arg(
+                            Arg::with_name("ifNewer")
+                                .long("if-newer")
+                                .conflicts_with_all(&["overwrite", "skipExisting", "rename"])
+                                .help("Replace the local file only if the file in Box was modified after it"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("verify")
+                        .about("Check that a local file has the same content as the file")
+                        .arg(
+                            Arg::with_name("path")
+                                .help("The path of the local file")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("upload-version")
+                        .about("Upload new content for the file")
+                        .arg(
+                            Arg::with_name("path")
+                                .help("The path of the new content on disk")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("ifMatch")
+                                .help("Only upload if the file's etag matches this value")
+                                .long("if-match")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("Renames the file")
+                                .long("name")
+                                .takes_value(true),
+                        )
+                        .args(&chunked_upload_args()),
                 )
                 .subcommand(
                     SubCommand::with_name("update")
@@ -84,7 +299,297 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 .help("Sets the description of the file")
                                 .long("description")
                                 .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("clearDescription")
+                                .help("Removes the description of the file")
+                                .long("clear-description")
+                                .conflicts_with("description"),
+                        )
+                        .arg(
+                            Arg::with_name("addTag")
+                                .help("Adds a tag to the file")
+                                .long("add-tag")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("removeTag")
+                                .help("Removes a tag from the file")
+                                .long("remove-tag")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("addCollection")
+                                .help("Adds the file to a collection")
+                                .long("collection")
+                                .value_name("COLLECTION_ID")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("removeCollection")
+                                .help("Removes the file from a collection")
+                                .long("remove-collection")
+                                .value_name("COLLECTION_ID")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("dispositionAt")
+                                .help("Sets when the file can be disposed of under its retention policy")
+                                .long("disposition-at")
+                                .value_name("RFC3339_DATE")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("clearDispositionAt")
+                                .help("Removes the disposition date of the file")
+                                .long("clear-disposition-at")
+                                .conflicts_with("dispositionAt"),
+                        )
+                        .arg(
+                            Arg::with_name("sharedLinkAccess")
+                                .help("Creates a shared link for the file, or sets who can use the existing one")
+                                .long("shared-link-access")
+                                .possible_values(&["open", "company", "collaborators"])
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("sharedLinkPassword")
+                                .help("Sets the password of the file's shared link")
+                                .long("shared-link-password")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("clearSharedLinkPassword")
+                                .help("Removes the password of the file's shared link")
+                                .long("clear-shared-link-password")
+                                .conflicts_with("sharedLinkPassword"),
+                        )
+                        .arg(
+                            Arg::with_name("sharedLinkUnsharedAt")
+                                .help("Sets when the file's shared link expires")
+                                .long("shared-link-unshared-at")
+                                .value_name("RFC3339_DATE")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("clearSharedLinkUnsharedAt")
+                                .help("Makes the file's shared link never expire")
+                                .long("clear-shared-link-unshared-at")
+                                .conflicts_with("sharedLinkUnsharedAt"),
+                        )
+                        .arg(
+                            Arg::with_name("sharedLinkCanDownload")
+                                .help("Sets whether the file can be downloaded through its shared link")
+                                .long("shared-link-can-download")
+                                .possible_values(&["true", "false"])
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("removeSharedLink")
+                                .help("Removes the file's shared link")
+                                .long("remove-shared-link")
+                                .conflicts_with_all(&[
+                                    "sharedLinkAccess",
+                                    "sharedLinkPassword",
+                                    "clearSharedLinkPassword",
+                                    "sharedLinkUnsharedAt",
+                                    "clearSharedLinkUnsharedAt",
+                                    "sharedLinkCanDownload",
+                                ]),
+                        )
+                        .arg(
+                            Arg::with_name("canDownload")
+                                .help("Sets who can download the file")
+                                .long("can-download")
+                                .possible_values(&["open", "company"])
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("copy")
+                        .about("Copy the file into another folder")
+                        .arg(
+                            Arg::with_name("folderID")
+                                .help("The ID of the folder to copy the file into")
+                                .long("to")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("The name of the copy")
+                                .long("name")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("version")
+                                .help("The ID of the version of the file to copy")
+                                .long("version")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("rename")
+                                .help("Name the copy \"name (1)\" if the name is already in use")
+                                .long("rename"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("move")
+                        .about("Move the file into another folder")
+                        .arg(
+                            Arg::with_name("folderID")
+                                .help("The ID of the folder to move the file into")
+                                .long("to")
+                                .takes_value(true)
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("lock")
+                        .about("Lock the file so only you can change it")
+                        .arg(
+                            Arg::with_name("expires")
+                                .help("How long until the lock expires, e.g. 30m, 2h or 7d")
+                                .long("expires")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("preventDownload")
+                                .help("Only let you download the file while it's locked")
+                                .long("prevent-download"),
+                        ),
+                )
+                .subcommand(SubCommand::with_name("unlock").about("Unlock the file"))
+                .subcommand(
+                    SubCommand::with_name("comments")
+                        .about("List the comments on the file, grouped into threads"),
+                )
+                .subcommand(
+                    SubCommand::with_name("rep")
+                        .about("Work with thumbnails, PDFs and other representations of the file")
+                        .setting(AppSettings::SubcommandRequiredElseHelp)
+                        .subcommand(
+                            SubCommand::with_name("list")
+                                .about("List the representations available for the file"),
+                        )
+                        .subcommand(
+                            SubCommand::with_name("get")
+                                .about("Download a representation, waiting for it to be generated")
+                                .arg(
+                                    Arg::with_name("type")
+                                        .help("The representation, e.g. png, jpg, pdf or extracted_text")
+                                        .required(true),
+                                )
+                                .arg(
+                                    Arg::with_name("size")
+                                        .help("The dimensions of the image, e.g. 320x320")
+                                        .long("size")
+                                        .takes_value(true),
+                                )
+                                .arg(
+                                    Arg::with_name("page")
+                                        .help("The page to download, for representations split into pages")
+                                        .long("page")
+                                        .takes_value(true),
+                                )
+                                .arg(
+                                    Arg::with_name("output")
+                                        .help("Where to save the representation")
+                                        .long("output")
+                                        .short("o")
+                                        .takes_value(true)
+                                        .required(true),
+                                ),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("versions")
+                        .about("List the previous versions of the file")
+                        .subcommand(
+                            SubCommand::with_name("download")
+                                .about("Download a previous version of the file")
+                                .arg(
+                                    Arg::with_name("versionID")
+                                        .help("The ID of the version")
+                                        .required(true),
+                                )
+                                .arg(Arg::with_name("path").default_value(".")),
+                        )
+                        .subcommand(
+                            SubCommand::with_name("promote")
+                                .about("Make a previous version the current version of the file")
+                                .arg(
+                                    Arg::with_name("versionID")
+                                        .help("The ID of the version")
+                                        .required(true),
+                                ),
                         ),
+                )
+                .subcommand(metadata_subcommand()),
+        )
+        .subcommand(
+            SubCommand::with_name("zip")
+                .about("Download several files and folders as one zip archive")
+                .arg(
+                    Arg::with_name("fileID")
+                        .help("The ID of a file to add to the archive")
+                        .long("file")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .arg(
+                    Arg::with_name("folderID")
+                        .help("The ID of a folder to add to the archive")
+                        .long("folder")
+                        .takes_value(true)
+                        .multiple(true)
+                        .number_of_values(1),
+                )
+                .group(
+                    ArgGroup::with_name("items")
+                        .args(&["fileID", "folderID"])
+                        .multiple(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("output")
+                        .help("Where to save the archive, or - for stdout")
+                        .short("o")
+                        .long("output")
+                        .takes_value(true)
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("name")
+                        .help("The name Box gives the archive, defaulting to the output file name")
+                        .long("name")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("overwrite")
+                        .long("overwrite")
+                        .help("Replace the output file if it already exists"),
+                )
+                .arg(
+                    Arg::with_name("skipExisting")
+                        .long("skip-existing")
+                        .conflicts_with("overwrite")
+                        .help("Keep the output file if it already exists"),
+                )
+                .arg(
+                    Arg::with_name("rename")
+                        .long("rename")
+                        .conflicts_with_all(&["overwrite", "skipExisting"])
+                        .help("Save next to the output file as \"name (1)\" if it already exists"),
                 ),
         )
         .subcommand(
@@ -92,14 +597,39 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 .about("Upload a file")
                 .arg(
                     Arg::with_name("path")
-                        .help("The path of the file on disk")
+                        .help("The path of the file on disk, or - to read from stdin")
                         .required(true),
                 )
+                .arg(
+                    Arg::with_name("name")
+                        .help("The name of the new file when reading from stdin")
+                        .long("name")
+                        .takes_value(true),
+                )
+                .arg(
+                    Arg::with_name("size")
+                        .help("The number of bytes that will be read from stdin, if known")
+                        .long("size")
+                        .takes_value(true),
+                )
                 .arg(
                     Arg::with_name("folderID")
                         .long("folder-id")
                         .short("D")
                         .takes_value(true),
+                )
+                .args(&chunked_upload_args())
+                .arg(
+                    Arg::with_name("resume")
+                        .help("Resume an interrupted chunked upload of the file")
+                        .long("resume")
+                        .conflicts_with("folderID"),
+                )
+                .arg(
+                    Arg::with_name("checkOnly")
+                        .help("Check that the file can be uploaded without uploading it")
+                        .long("check-only")
+                        .conflicts_with("resume"),
                 ),
         )
         .subcommand(
@@ -119,42 +649,570 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 .short("n")
                                 .default_value("100"),
                         ),
-                ),
+                )
+                .subcommand(
+                    SubCommand::with_name("copy")
+                        .about("Copy the folder and its contents into another folder")
+                        .arg(
+                            Arg::with_name("folderID")
+                                .help("The ID of the folder to copy the folder into")
+                                .long("to")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("The name of the copy")
+                                .long("name")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("rename")
+                                .help("Name the copy \"name (1)\" if the name is already in use")
+                                .long("rename"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("move")
+                        .about("Move the folder into another folder")
+                        .arg(
+                            Arg::with_name("folderID")
+                                .help("The ID of the folder to move the folder into")
+                                .long("to")
+                                .takes_value(true)
+                                .required(true),
+                        ),
+                )
+                .subcommand(metadata_subcommand()),
         )
         .subcommand(
-            SubCommand::with_name("user")
-                .about("Display information about a user")
+            SubCommand::with_name("weblink")
+                .about("Displays information about a web link")
+                .setting(AppSettings::SubcommandsNegateReqs)
                 .arg(
                     Arg::with_name("id")
-                        .help("The ID of the user")
-                        .default_value("me"),
+                        .help("The ID of the web link")
+                        .required(true),
+                )
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .about("Create a web link")
+                        .arg(
+                            Arg::with_name("url")
+                                .help("The URL the web link points to")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("folderID")
+                                .long("folder-id")
+                                .short("D")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("The name of the web link")
+                                .long("name")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("description")
+                                .help("The description of the web link")
+                                .long("description")
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(SubCommand::with_name("delete"))
+                .subcommand(
+                    SubCommand::with_name("move").arg(
+                        Arg::with_name("folderID")
+                            .help("The ID of the destination folder")
+                            .long("to")
+                            .takes_value(true)
+                            .required(true),
+                    ),
+                )
+                .subcommand(
+                    SubCommand::with_name("update")
+                        .arg(
+                            Arg::with_name("url")
+                                .help("Sets the URL of the web link")
+                                .long("url")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("name")
+                                .help("Sets the name of the web link")
+                                .long("name")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("description")
+                                .help("Sets the description of the web link")
+                                .long("description")
+                                .takes_value(true),
+                        ),
                 ),
-        );
-
-    let matches = app.get_matches();
-
-    let token = matches.value_of("token").expect("Token must be provided!");
-
-    let mut context = Context {
-        client: Client::new(token.to_owned()),
-        fmt: if matches.is_present("json") {
-            OutputFormat::JSON
-        } else {
-            OutputFormat::Debug
-        },
-    };
-
-    // OBJECT: file
-    if let Some(matches) = matches.subcommand_matches("file") {
-        let file_id = matches.value_of("fileID").unwrap();
-
-        // ACTION: delete
+        )
+        .subcommand(
+            SubCommand::with_name("comment")
+                .about("Displays a comment")
+                .setting(AppSettings::SubcommandsNegateReqs)
+                .arg(
+                    Arg::with_name("id")
+                        .help("The ID of the comment")
+                        .required(true),
+                )
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .about("Comment on a file, mentioning users with @[id:name]")
+                        .arg(
+                            Arg::with_name("message")
+                                .help("The text of the comment")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("fileID")
+                                .help("The ID of the file to comment on")
+                                .long("file")
+                                .takes_value(true)
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("reply")
+                        .about("Reply to the comment, mentioning users with @[id:name]")
+                        .arg(
+                            Arg::with_name("message")
+                                .help("The text of the reply")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("update").arg(
+                        Arg::with_name("message")
+                            .help("The new text of the comment")
+                            .required(true),
+                    ),
+                )
+                .subcommand(SubCommand::with_name("delete")),
+        )
+        .subcommand(
+            SubCommand::with_name("task")
+                .about("Displays a task")
+                .setting(AppSettings::SubcommandsNegateReqs)
+                .arg(
+                    Arg::with_name("id")
+                        .help("The ID of the task")
+                        .required(true),
+                )
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .about("Create a task on a file")
+                        .arg(
+                            Arg::with_name("fileID")
+                                .help("The ID of the file")
+                                .long("file")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("action")
+                                .help("Whether assignees review the file or just complete the task")
+                                .long("action")
+                                .possible_values(&["review", "complete"])
+                                .default_value("review"),
+                        )
+                        .arg(
+                            Arg::with_name("message")
+                                .help("What the assignees should do")
+                                .long("message")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("due")
+                                .help("When the task is due, as an RFC 3339 date or a duration like 2d")
+                                .long("due")
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List the tasks on a file")
+                        .arg(
+                            Arg::with_name("fileID")
+                                .help("The ID of the file")
+                                .long("file")
+                                .takes_value(true)
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("resolve")
+                        .about("Resolve a task assignment")
+                        .arg(
+                            Arg::with_name("assignmentID")
+                                .help("The ID of the task assignment")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("state")
+                                .help("The resolution of the assignment")
+                                .possible_values(&["approved", "rejected", "completed", "incomplete"])
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("message")
+                                .help("A message to go with the resolution")
+                                .long("message")
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("assign")
+                        .about("Assign the task to a user")
+                        .arg(
+                            Arg::with_name("userID")
+                                .help("The ID of the user")
+                                .long("user")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("login")
+                                .help("The email address of the user")
+                                .long("login")
+                                .takes_value(true),
+                        )
+                        .group(
+                            ArgGroup::with_name("assignee")
+                                .args(&["userID", "login"])
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("assignments").about("List the assignments of the task"),
+                )
+                .subcommand(SubCommand::with_name("delete")),
+        )
+        .subcommand(
+            SubCommand::with_name("user")
+                .about("Display information about a user")
+                .arg(
+                    Arg::with_name("id")
+                        .help("The ID of the user")
+                        .default_value("me"),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("metadata")
+                .about("Search for items by their metadata")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("query")
+                        .about("List the items with metadata that matches a query")
+                        .arg(
+                            Arg::with_name("from")
+                                .help("The template to query, as SCOPE.TEMPLATE")
+                                .long("from")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("where")
+                                .help("A condition on the template's fields, e.g. \"amount > :min\"")
+                                .long("where")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("param")
+                                .help("A value for the query as NAME=VALUE; numbers are sent as numbers")
+                                .long("param")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("ancestor")
+                                .help("Only search in this folder and its subfolders")
+                                .long("ancestor")
+                                .default_value("0"),
+                        )
+                        .arg(
+                            Arg::with_name("orderBy")
+                                .help("A field to sort by, as FIELD or FIELD:desc")
+                                .long("order-by")
+                                .takes_value(true)
+                                .multiple(true)
+                                .number_of_values(1),
+                        )
+                        .arg(
+                            Arg::with_name("fields")
+                                .help("The fields to return for each item")
+                                .long("fields")
+                                .takes_value(true)
+                                .use_delimiter(true),
+                        )
+                        .arg(
+                            Arg::with_name("limit")
+                                .help("The number of items to fetch in each request")
+                                .long("limit")
+                                .short("n")
+                                .default_value("100"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("export")
+                        .about("Write the metadata on the files in a folder as CSV")
+                        .arg(
+                            Arg::with_name("template")
+                                .help("The template to export, as SCOPE.TEMPLATE")
+                                .long("template")
+                                .takes_value(true)
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("folderID")
+                                .help("The ID of the folder")
+                                .long("folder")
+                                .default_value("0"),
+                        )
+                        .arg(
+                            Arg::with_name("recursive")
+                                .help("Include the files in subfolders")
+                                .long("recursive")
+                                .short("r"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("import")
+                        .about("Apply metadata to files from a CSV with an id or path column")
+                        .arg(
+                            Arg::with_name("path")
+                                .help("The CSV file")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("template")
+                                .help("The template to apply, as SCOPE.TEMPLATE, found from the CSV's columns if left out")
+                                .long("template")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("dryRun")
+                                .help("Show what would change without changing anything")
+                                .long("dry-run"),
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("metadata-template")
+                .about("Manage the metadata templates in an enterprise")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List metadata templates")
+                        .arg(
+                            Arg::with_name("scope")
+                                .help("Only list the templates in this scope")
+                                .long("scope")
+                                .possible_values(&["enterprise", "global"])
+                                .takes_value(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("get")
+                        .about("Display a metadata template")
+                        .arg(
+                            Arg::with_name("template")
+                                .help("The key of the metadata template")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("scope")
+                                .help("The scope of the metadata template")
+                                .long("scope")
+                                .default_value("enterprise"),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("create")
+                        .about("Create a metadata template from a JSON or YAML definition")
+                        .arg(
+                            Arg::with_name("path")
+                                .help("The definition file")
+                                .required(true),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("update")
+                        .about("Change the fields of a metadata template")
+                        .arg(
+                            Arg::with_name("template")
+                                .help("The key of the metadata template")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("scope")
+                                .help("The scope of the metadata template")
+                                .long("scope")
+                                .default_value("enterprise"),
+                        )
+                        .arg(
+                            Arg::with_name("data")
+                                .help("A JSON or YAML file with a list of template operations")
+                                .long("data")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("reorderFields")
+                                .help("The keys of all the fields, in their new order")
+                                .long("reorder-fields")
+                                .takes_value(true)
+                                .use_delimiter(true),
+                        )
+                        .arg(
+                            Arg::with_name("addEnumOption")
+                                .help("Add an option to an enum field")
+                                .long("add-enum-option")
+                                .value_names(&["FIELD", "OPTION"])
+                                .multiple(true)
+                                .number_of_values(2),
+                        )
+                        .arg(
+                            Arg::with_name("editEnumOption")
+                                .help("Rename an option of an enum field")
+                                .long("edit-enum-option")
+                                .value_names(&["FIELD", "OPTION", "NEW_OPTION"])
+                                .multiple(true)
+                                .number_of_values(3),
+                        )
+                        .arg(
+                            Arg::with_name("removeEnumOption")
+                                .help("Remove an option from an enum field")
+                                .long("remove-enum-option")
+                                .value_names(&["FIELD", "OPTION"])
+                                .multiple(true)
+                                .number_of_values(2),
+                        ),
+                )
+                .subcommand(
+                    SubCommand::with_name("delete")
+                        .about("Delete a metadata template and all of its instances")
+                        .arg(
+                            Arg::with_name("template")
+                                .help("The key of the metadata template")
+                                .required(true),
+                        )
+                        .arg(
+                            Arg::with_name("scope")
+                                .help("The scope of the metadata template")
+                                .long("scope")
+                                .default_value("enterprise"),
+                        ),
+                ),
+        )
+        .subcommand(
+            SubCommand::with_name("users")
+                .about("Manage the users in an enterprise")
+                .setting(AppSettings::SubcommandRequiredElseHelp)
+                .subcommand(
+                    SubCommand::with_name("list")
+                        .about("List the users in the enterprise")
+                        .arg(
+                            Arg::with_name("filter")
+                                .help("Only return users whose name or login starts with this term")
+                                .long("filter")
+                                .short("f")
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("type")
+                                .help("The type of users to return")
+                                .long("type")
+                                .possible_values(&["all", "managed", "external"])
+                                .takes_value(true),
+                        )
+                        .arg(
+                            Arg::with_name("limit")
+                                .long("limit")
+                                .short("n")
+                                .default_value("100"),
+                        )
+                        .arg(Arg::with_name("offset").long("offset").default_value("0")),
+                ),
+        );
+
+    let matches = app.get_matches();
+
+    let token = matches.value_of("token").expect("Token must be provided!");
+
+    let mut client = Client::new(token.to_owned());
+    let upload_matches = matches.subcommand_matches("upload").or_else(|| {
+        matches
+            .subcommand_matches("file")
+            .and_then(|matches| matches.subcommand_matches("upload-version"))
+    });
+    if let Some(matches) = upload_matches {
+        client = client
+            .with_chunked_upload_threshold(matches.value_of("chunkedThreshold").unwrap().parse()?)
+            .with_upload_concurrency(matches.value_of("concurrency").unwrap().parse()?);
+    }
+    let download_matches = matches
+        .subcommand_matches("file")
+        .and_then(|matches| matches.subcommand_matches("download"));
+    if let Some(matches) = download_matches {
+        client = client
+            .with_download_part_size(matches.value_of("partSize").unwrap().parse()?)
+            .with_download_concurrency(matches.value_of("concurrency").unwrap().parse()?);
+    }
+
+    let mut context = Context {
+        client,
+        fmt: if matches.is_present("json") {
+            OutputFormat::JSON
+        } else {
+            OutputFormat::Debug
+        },
+    };
+
+    // OBJECT: file
+    if let Some(matches) = matches.subcommand_matches("file") {
+        let file_id = matches.value_of("fileID").unwrap();
+
+        // ACTION: delete
         if let Some(_matches) = matches.subcommand_matches("delete") {
             delete_file(&mut context, file_id).await?;
         // ACTION: download
         } else if let Some(matches) = matches.subcommand_matches("download") {
             let path = Path::new(matches.value_of("path").unwrap());
-            download_file(&mut context, file_id, path).await?;
+            if path == Path::new("-") {
+                if matches.is_present("range") || matches.is_present("parallel") {
+                    return Err(
+                        "--range and --parallel can't be used when downloading to stdout".into(),
+                    );
+                }
+                download_file_to_stdout(&mut context, file_id).await?;
+            } else {
+                let policy = existing_file_policy(matches);
+                let mode = if matches.is_present("parallel") {
+                    DownloadMode::Parallel
+                } else if let Some(range) = matches.value_of("range") {
+                    let (start, end) = parse_range(range)?;
+                    DownloadMode::Range(start, end)
+                } else {
+                    DownloadMode::Whole
+                };
+                download_file(&mut context, file_id, path, policy, mode).await?;
+            }
+        // ACTION: verify
+        } else if let Some(matches) = matches.subcommand_matches("verify") {
+            let path = Path::new(matches.value_of("path").unwrap());
+            verify_file(&mut context, file_id, path).await?;
+        // ACTION: upload-version
+        } else if let Some(matches) = matches.subcommand_matches("upload-version") {
+            let path = Path::new(matches.value_of("path").unwrap());
+            let if_match = matches.value_of("ifMatch");
+            let name = matches.value_of("name");
+            upload_file_version(&mut context, file_id, path, if_match, name).await?;
         // ACTION: update
         } else if let Some(matches) = matches.subcommand_matches("update") {
             let mut updates = FileUpdates::new();
@@ -164,18 +1222,150 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             if let Some(description) = matches.value_of("description") {
                 updates = updates.description(description);
             }
+            if matches.is_present("clearDescription") {
+                updates = updates.clear_description();
+            }
+            if let Some(disposition_at) = matches.value_of("dispositionAt") {
+                updates = updates.disposition_at(DateTime::parse_from_rfc3339(disposition_at)?);
+            }
+            if matches.is_present("clearDispositionAt") {
+                updates = updates.clear_disposition_at();
+            }
+            if matches.is_present("removeSharedLink") {
+                updates = updates.remove_shared_link();
+            } else if let Some(shared_link) = shared_link_updates(matches)? {
+                updates = updates.shared_link(shared_link);
+            }
+            match matches.value_of("canDownload") {
+                Some("open") => updates = updates.can_download(DownloadPermission::Open),
+                Some("company") => updates = updates.can_download(DownloadPermission::Company),
+                _ => {}
+            }
+            let add_tags: Vec<&str> = matches.values_of("addTag").unwrap_or_default().collect();
+            let remove_tags: Vec<&str> =
+                matches.values_of("removeTag").unwrap_or_default().collect();
+            let add_collections: Vec<&str> = matches
+                .values_of("addCollection")
+                .unwrap_or_default()
+                .collect();
+            let remove_collections: Vec<&str> = matches
+                .values_of("removeCollection")
+                .unwrap_or_default()
+                .collect();
 
-            update_file(&mut context, file_id, updates).await?;
+            update_file(
+                &mut context,
+                file_id,
+                updates,
+                (&add_tags, &remove_tags),
+                (&add_collections, &remove_collections),
+            )
+            .await?;
+        // ACTION: copy
+        } else if let Some(matches) = matches.subcommand_matches("copy") {
+            let folder_id = matches.value_of("folderID").unwrap();
+            let name = matches.value_of("name");
+            let version = matches.value_of("version");
+            let rename = matches.is_present("rename");
+            copy_file(&mut context, file_id, folder_id, name, version, rename).await?;
+        // ACTION: move
+        } else if let Some(matches) = matches.subcommand_matches("move") {
+            let folder_id = matches.value_of("folderID").unwrap();
+            move_file(&mut context, file_id, folder_id).await?;
+        // ACTION: lock
+        } else if let Some(matches) = matches.subcommand_matches("lock") {
+            let expires_at = match matches.value_of("expires") {
+                Some(expires) => Some(from_now(expires)?.into()),
+                None => None,
+            };
+            let prevent_download = matches.is_present("preventDownload");
+            lock_file(&mut context, file_id, expires_at, prevent_download).await?;
+        // ACTION: unlock
+        } else if let Some(_matches) = matches.subcommand_matches("unlock") {
+            unlock_file(&mut context, file_id).await?;
+        // ACTION: comments
+        } else if let Some(_matches) = matches.subcommand_matches("comments") {
+            list_comments(&mut context, file_id).await?;
+        // ACTION: rep
+        } else if let Some(matches) = matches.subcommand_matches("rep") {
+            if let Some(matches) = matches.subcommand_matches("get") {
+                let rep_type = matches.value_of("type").unwrap();
+                let size = matches.value_of("size");
+                let asset_path = match matches.value_of("page") {
+                    Some(page) => format!("{}.{}", page, rep_type),
+                    None => String::new(),
+                };
+                let path = Path::new(matches.value_of("output").unwrap());
+                get_representation(&mut context, file_id, rep_type, size, &asset_path, path)
+                    .await?;
+            } else {
+                list_representations(&mut context, file_id).await?;
+            }
+        // ACTION: versions
+        } else if let Some(matches) = matches.subcommand_matches("versions") {
+            if let Some(matches) = matches.subcommand_matches("download") {
+                let version_id = matches.value_of("versionID").unwrap();
+                let path = Path::new(matches.value_of("path").unwrap());
+                download_file_version(&mut context, file_id, version_id, path).await?;
+            } else if let Some(matches) = matches.subcommand_matches("promote") {
+                let version_id = matches.value_of("versionID").unwrap();
+                promote_file_version(&mut context, file_id, version_id).await?;
+            } else {
+                list_file_versions(&mut context, file_id).await?;
+            }
+        // ACTION: metadata
+        } else if let Some(matches) = matches.subcommand_matches("metadata") {
+            metadata_action(&mut context, MetadataTarget::File(file_id), matches).await?;
         // DEFAULT ACTION: get
         } else {
             get_file(&mut context, file_id).await?;
         }
 
+    // COMMAND: zip
+    } else if let Some(matches) = matches.subcommand_matches("zip") {
+        let mut zip = NewZipDownload::new();
+        for file_id in matches.values_of("fileID").into_iter().flatten() {
+            zip = zip.file(file_id);
+        }
+        for folder_id in matches.values_of("folderID").into_iter().flatten() {
+            zip = zip.folder(folder_id);
+        }
+        let path = Path::new(matches.value_of("output").unwrap());
+        let name = match matches.value_of("name") {
+            Some(name) => Some(name),
+            None if path == Path::new("-") => None,
+            None => path.file_stem().and_then(|stem| stem.to_str()),
+        };
+        if let Some(name) = name {
+            zip = zip.name(name);
+        }
+        let policy = existing_file_policy(matches);
+        download_zip(&mut context, zip, path, policy).await?;
+
     // COMMAND: upload
     } else if let Some(matches) = matches.subcommand_matches("upload") {
         let path = Path::new(matches.value_of("path").unwrap());
-        let folder_id = matches.value_of("folderID").unwrap_or("0");
-        upload_file(&mut context, path, folder_id).await?;
+        if path == Path::new("-") {
+            if matches.is_present("resume") || matches.is_present("checkOnly") {
+                return Err(
+                    "--resume and --check-only can't be used when uploading from stdin".into(),
+                );
+            }
+            let name = matches
+                .value_of("name")
+                .ok_or("--name is required when uploading from stdin")?;
+            let size = matches.value_of("size").map(str::parse).transpose()?;
+            let folder_id = matches.value_of("folderID").unwrap_or("0");
+            upload_stdin(&mut context, name, folder_id, size).await?;
+        } else if matches.is_present("resume") {
+            resume_upload(&mut context, path).await?;
+        } else if matches.is_present("checkOnly") {
+            let folder_id = matches.value_of("folderID").unwrap_or("0");
+            preflight_upload(&mut context, path, folder_id).await?;
+        } else {
+            let folder_id = matches.value_of("folderID").unwrap_or("0");
+            upload_file(&mut context, path, folder_id).await?;
+        }
 
     // COMMAND: folder
     } else if let Some(matches) = matches.subcommand_matches("folder") {
@@ -183,13 +1373,291 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         if let Some(matches) = matches.subcommand_matches("items") {
             let limit = matches.value_of("limit").unwrap().parse()?;
             get_folder_items(&mut context, id, limit).await?;
+        } else if let Some(matches) = matches.subcommand_matches("copy") {
+            let folder_id = matches.value_of("folderID").unwrap();
+            let name = matches.value_of("name");
+            let rename = matches.is_present("rename");
+            copy_folder(&mut context, id, folder_id, name, rename).await?;
+        } else if let Some(matches) = matches.subcommand_matches("move") {
+            let folder_id = matches.value_of("folderID").unwrap();
+            move_folder(&mut context, id, folder_id).await?;
+        } else if let Some(matches) = matches.subcommand_matches("metadata") {
+            metadata_action(&mut context, MetadataTarget::Folder(id), matches).await?;
         } else {
             get_folder(&mut context, id).await?;
         }
+    // OBJECT: comment
+    } else if let Some(matches) = matches.subcommand_matches("comment") {
+        // ACTION: create
+        if let Some(matches) = matches.subcommand_matches("create") {
+            let message = matches.value_of("message").unwrap();
+            let file_id = matches.value_of("fileID").unwrap();
+            create_comment(&mut context, file_id, message).await?;
+        } else {
+            let id = matches
+                .value_of("id")
+                .ok_or("The ID of the comment must be provided")?;
+
+            // ACTION: reply
+            if let Some(matches) = matches.subcommand_matches("reply") {
+                let message = matches.value_of("message").unwrap();
+                reply_to_comment(&mut context, id, message).await?;
+            // ACTION: update
+            } else if let Some(matches) = matches.subcommand_matches("update") {
+                let message = matches.value_of("message").unwrap();
+                update_comment(&mut context, id, message).await?;
+            // ACTION: delete
+            } else if let Some(_matches) = matches.subcommand_matches("delete") {
+                delete_comment(&mut context, id).await?;
+            // DEFAULT ACTION: get
+            } else {
+                get_comment(&mut context, id).await?;
+            }
+        }
+    // OBJECT: task
+    } else if let Some(matches) = matches.subcommand_matches("task") {
+        // ACTION: create
+        if let Some(matches) = matches.subcommand_matches("create") {
+            let file_id = matches.value_of("fileID").unwrap();
+            let action = match matches.value_of("action").unwrap() {
+                "complete" => TaskAction::Complete,
+                _ => TaskAction::Review,
+            };
+            let mut task = NewTask::new(file_id, action);
+            if let Some(message) = matches.value_of("message") {
+                task = task.message(message);
+            }
+            if let Some(due) = matches.value_of("due") {
+                let due_at = match DateTime::parse_from_rfc3339(due) {
+                    Ok(due_at) => due_at,
+                    Err(_) => from_now(due)?.into(),
+                };
+                task = task.due_at(due_at);
+            }
+            create_task(&mut context, task).await?;
+        // ACTION: list
+        } else if let Some(matches) = matches.subcommand_matches("list") {
+            let file_id = matches.value_of("fileID").unwrap();
+            list_tasks(&mut context, file_id).await?;
+        // ACTION: resolve
+        } else if let Some(matches) = matches.subcommand_matches("resolve") {
+            let assignment_id = matches.value_of("assignmentID").unwrap();
+            let state = match matches.value_of("state").unwrap() {
+                "approved" => ResolutionState::Approved,
+                "rejected" => ResolutionState::Rejected,
+                "completed" => ResolutionState::Completed,
+                _ => ResolutionState::Incomplete,
+            };
+            let message = matches.value_of("message");
+            resolve_task_assignment(&mut context, assignment_id, state, message).await?;
+        } else {
+            let id = matches
+                .value_of("id")
+                .ok_or("The ID of the task must be provided")?;
+
+            // ACTION: assign
+            if let Some(matches) = matches.subcommand_matches("assign") {
+                let user_id = matches.value_of("userID");
+                let login = matches.value_of("login");
+                assign_task(&mut context, id, user_id, login).await?;
+            // ACTION: assignments
+            } else if let Some(_matches) = matches.subcommand_matches("assignments") {
+                list_task_assignments(&mut context, id).await?;
+            // ACTION: delete
+            } else if let Some(_matches) = matches.subcommand_matches("delete") {
+                delete_task(&mut context, id).await?;
+            // DEFAULT ACTION: get
+            } else {
+                get_task(&mut context, id).await?;
+            }
+        }
+    // OBJECT: weblink
+    } else if let Some(matches) = matches.subcommand_matches("weblink") {
+        // ACTION: create
+        if let Some(matches) = matches.subcommand_matches("create") {
+            let url = matches.value_of("url").unwrap();
+            let folder_id = matches.value_of("folderID").unwrap_or("0");
+            let mut web_link = NewWebLink::new(url, folder_id);
+            if let Some(name) = matches.value_of("name") {
+                web_link = web_link.name(name);
+            }
+            if let Some(description) = matches.value_of("description") {
+                web_link = web_link.description(description);
+            }
+            create_web_link(&mut context, web_link).await?;
+        } else {
+            let id = matches
+                .value_of("id")
+                .ok_or("The ID of the web link must be provided")?;
+
+            // ACTION: delete
+            if let Some(_matches) = matches.subcommand_matches("delete") {
+                delete_web_link(&mut context, id).await?;
+            // ACTION: move
+            } else if let Some(matches) = matches.subcommand_matches("move") {
+                let folder_id = matches.value_of("folderID").unwrap();
+                move_web_link(&mut context, id, folder_id).await?;
+            // ACTION: update
+            } else if let Some(matches) = matches.subcommand_matches("update") {
+                let mut updates = WebLinkUpdates::new();
+                if let Some(url) = matches.value_of("url") {
+                    updates = updates.url(url);
+                }
+                if let Some(name) = matches.value_of("name") {
+                    updates = updates.name(name);
+                }
+                if let Some(description) = matches.value_of("description") {
+                    updates = updates.description(description);
+                }
+
+                update_web_link(&mut context, id, updates).await?;
+            // DEFAULT ACTION: get
+            } else {
+                get_web_link(&mut context, id).await?;
+            }
+        }
+
     // COMMAND: user
     } else if let Some(matches) = matches.subcommand_matches("user") {
         let id = matches.value_of("id").unwrap();
         get_user(&mut context, id).await?;
+    // COMMAND: users
+    } else if let Some(matches) = matches.subcommand_matches("users") {
+        if let Some(matches) = matches.subcommand_matches("list") {
+            let mut query = UserQuery::new()
+                .limit(matches.value_of("limit").unwrap().parse()?)
+                .offset(matches.value_of("offset").unwrap().parse()?);
+            if let Some(filter_term) = matches.value_of("filter") {
+                query = query.filter_term(filter_term);
+            }
+            if let Some(user_type) = matches.value_of("type") {
+                query = query.user_type(match user_type {
+                    "managed" => UserType::Managed,
+                    "external" => UserType::External,
+                    _ => UserType::All,
+                });
+            }
+            list_users(&mut context, query).await?;
+        }
+    // OBJECT: metadata
+    } else if let Some(matches) = matches.subcommand_matches("metadata") {
+        // ACTION: query
+        if let Some(matches) = matches.subcommand_matches("query") {
+            let from = matches.value_of("from").unwrap();
+            let ancestor = matches.value_of("ancestor").unwrap();
+            let mut query = MetadataQuery::new(from, ancestor)
+                .limit(matches.value_of("limit").unwrap().parse()?);
+            if let Some(condition) = matches.value_of("where") {
+                query = query.query(condition);
+            }
+            for param in matches.values_of("param").into_iter().flatten() {
+                let (name, value) = parse_query_param(param)?;
+                query = query.param(name, value);
+            }
+            for order in matches.values_of("orderBy").into_iter().flatten() {
+                query = match order.rsplit_once(':') {
+                    Some((field, "desc")) => query.order_by(field, SortDirection::Descending),
+                    Some((field, "asc")) => query.order_by(field, SortDirection::Ascending),
+                    _ => query.order_by(order, SortDirection::Ascending),
+                };
+            }
+            if let Some(fields) = matches.values_of("fields") {
+                query = query.fields(&fields.collect::<Vec<_>>());
+            }
+            query_metadata(&mut context, query).await?;
+        // ACTION: export
+        } else if let Some(matches) = matches.subcommand_matches("export") {
+            let template = matches.value_of("template").unwrap();
+            let folder_id = matches.value_of("folderID").unwrap();
+            let recursive = matches.is_present("recursive");
+            export_metadata(&mut context, template, folder_id, recursive).await?;
+        // ACTION: import
+        } else if let Some(matches) = matches.subcommand_matches("import") {
+            let path = Path::new(matches.value_of("path").unwrap());
+            let template = matches.value_of("template");
+            let dry_run = matches.is_present("dryRun");
+            import_metadata(&mut context, path, template, dry_run).await?;
+        }
+    // OBJECT: metadata-template
+    } else if let Some(matches) = matches.subcommand_matches("metadata-template") {
+        // ACTION: list
+        if let Some(matches) = matches.subcommand_matches("list") {
+            let scopes = match matches.value_of("scope") {
+                Some(scope) => vec![scope],
+                None => vec!["enterprise", "global"],
+            };
+            list_metadata_templates(&mut context, &scopes).await?;
+        // ACTION: get
+        } else if let Some(matches) = matches.subcommand_matches("get") {
+            let scope = matches.value_of("scope").unwrap();
+            let template = matches.value_of("template").unwrap();
+            get_metadata_template(&mut context, scope, template).await?;
+        // ACTION: create
+        } else if let Some(matches) = matches.subcommand_matches("create") {
+            let path = Path::new(matches.value_of("path").unwrap());
+            let template: NewMetadataTemplate = read_definition(path).await?;
+            create_metadata_template(&mut context, template).await?;
+        // ACTION: update
+        } else if let Some(matches) = matches.subcommand_matches("update") {
+            let scope = matches.value_of("scope").unwrap();
+            let template = matches.value_of("template").unwrap();
+            // Changes are made in the order they're given on the command line,
+            // so that e.g. an option can be added and then renamed
+            let mut edits: Vec<(usize, TemplateEdit<'_>)> = Vec::new();
+            if let Some(path) = matches.value_of("data") {
+                let changes: Vec<MetadataTemplateChange> = read_definition(Path::new(path)).await?;
+                edits.push((
+                    matches.index_of("data").unwrap(),
+                    Box::new(move |updates| changes.into_iter().fold(updates, |u, c| u.change(c))),
+                ));
+            }
+            let values = |name| {
+                let indices = matches.indices_of(name).into_iter().flatten();
+                let values = matches.values_of(name).into_iter().flatten();
+                indices.zip(values).collect::<Vec<_>>()
+            };
+            let field_keys = values("reorderFields");
+            if let Some(&(index, _)) = field_keys.first() {
+                let field_keys: Vec<&str> = field_keys.iter().map(|(_, key)| *key).collect();
+                edits.push((
+                    index,
+                    Box::new(move |updates| updates.reorder_fields(&field_keys)),
+                ));
+            }
+            for option in values("addEnumOption").chunks(2) {
+                let (field, key) = (option[0].1, option[1].1);
+                edits.push((
+                    option[0].0,
+                    Box::new(move |updates| updates.add_enum_option(field, key)),
+                ));
+            }
+            for option in values("editEnumOption").chunks(3) {
+                let (field, key, new_key) = (option[0].1, option[1].1, option[2].1);
+                edits.push((
+                    option[0].0,
+                    Box::new(move |updates| updates.edit_enum_option(field, key, new_key)),
+                ));
+            }
+            for option in values("removeEnumOption").chunks(2) {
+                let (field, key) = (option[0].1, option[1].1);
+                edits.push((
+                    option[0].0,
+                    Box::new(move |updates| updates.remove_enum_option(field, key)),
+                ));
+            }
+            edits.sort_by_key(|(index, _)| *index);
+            let updates = edits
+                .into_iter()
+                .fold(MetadataTemplateUpdates::new(), |updates, (_, edit)| {
+                    edit(updates)
+                });
+            update_metadata_template(&mut context, scope, template, updates).await?;
+        // ACTION: delete
+        } else if let Some(matches) = matches.subcommand_matches("delete") {
+            let scope = matches.value_of("scope").unwrap();
+            let template = matches.value_of("template").unwrap();
+            delete_metadata_template(&mut context, scope, template).await?;
+        }
     }
 
     Ok(())
@@ -201,18 +1669,342 @@ async fn get_file(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
     Ok(())
 }
 
-async fn update_file(ctx: &mut Context, id: &str, updates: FileUpdates) -> Result<(), SDKError> {
+async fn update_file(
+    ctx: &mut Context,
+    id: &str,
+    mut updates: FileUpdates,
+    (add_tags, remove_tags): (&[&str], &[&str]),
+    (add_collections, remove_collections): (&[&str], &[&str]),
+) -> Result<(), SDKError> {
+    let edit_tags = !add_tags.is_empty() || !remove_tags.is_empty();
+    let edit_collections = !add_collections.is_empty() || !remove_collections.is_empty();
+
+    // Tags and collections can only be replaced as a whole, so start from the
+    // current ones
+    if edit_tags || edit_collections {
+        let mut fields = Vec::new();
+        if edit_tags {
+            fields.push("tags");
+        }
+        if edit_collections {
+            fields.push("collections");
+        }
+        let file = ctx.client.file(id).get_fields(&fields).await?;
+
+        if edit_tags {
+            let tags: Vec<&str> = file
+                .tags()
+                .unwrap_or_default()
+                .iter()
+                .map(String::as_str)
+                .collect();
+            updates = updates.tags(&edited(tags, add_tags, remove_tags));
+        }
+        if edit_collections {
+            let collections: Vec<&str> = file
+                .collections()
+                .unwrap_or_default()
+                .iter()
+                .map(FileCollection::id)
+                .collect();
+            updates =
+                updates.collections(&edited(collections, add_collections, remove_collections));
+        }
+    }
+
     let file = ctx.client.file(id).update(updates).await?;
     ctx.output(file);
     Ok(())
 }
 
-async fn download_file(ctx: &mut Context, id: &str, path: &Path) -> Result<(), SDKError> {
-    println!("Downloading file {}...", id);
-    ctx.client.file(id).download(path).await?;
-    println!("File {} downloaded to {}", id, path.to_str().unwrap());
-    Ok(())
-}
+/// Adds `add` to `values`, skipping any already there, then takes out `remove`.
+fn edited<'a>(mut values: Vec<&'a str>, add: &[&'a str], remove: &[&str]) -> Vec<&'a str> {
+    for value in add {
+        if !values.contains(value) {
+            values.push(value);
+        }
+    }
+    values.retain(|value| !remove.contains(value));
+    values
+}
+
+/// Builds the shared link changes asked for by the `--shared-link-*` flags, if
+/// there are any.
+fn shared_link_updates(
+    matches: &ArgMatches<'_>,
+) -> Result<Option<SharedLinkUpdates>, Box<dyn std::error::Error>> {
+    let mut updates = SharedLinkUpdates::new();
+    let mut changed = false;
+    if let Some(access) = matches.value_of("sharedLinkAccess") {
+        updates = updates.access(match access {
+            "open" => SharedLinkAccess::Open,
+            "company" => SharedLinkAccess::Company,
+            _ => SharedLinkAccess::Collaborators,
+        });
+        changed = true;
+    }
+    if let Some(password) = matches.value_of("sharedLinkPassword") {
+        updates = updates.password(password);
+        changed = true;
+    }
+    if matches.is_present("clearSharedLinkPassword") {
+        updates = updates.clear_password();
+        changed = true;
+    }
+    if let Some(unshared_at) = matches.value_of("sharedLinkUnsharedAt") {
+        updates = updates.unshared_at(DateTime::parse_from_rfc3339(unshared_at)?);
+        changed = true;
+    }
+    if matches.is_present("clearSharedLinkUnsharedAt") {
+        updates = updates.clear_unshared_at();
+        changed = true;
+    }
+    if let Some(can_download) = matches.value_of("sharedLinkCanDownload") {
+        updates = updates.can_download(can_download == "true");
+        changed = true;
+    }
+    Ok(if changed { Some(updates) } else { None })
+}
+
+async fn download_file(
+    ctx: &mut Context,
+    id: &str,
+    path: &Path,
+    policy: ExistingFilePolicy,
+    mode: DownloadMode,
+) -> Result<(), SDKError> {
+    let path = match ctx.client.file(id).destination(path, policy).await? {
+        Destination::Download(path) => path,
+        Destination::Skip(path) => {
+            println!("File {} skipped, {} already exists", id, path.display());
+            return Ok(());
+        }
+    };
+
+    println!("Downloading file {}...", id);
+    let mut file = ctx.client.file(id);
+    match mode {
+        DownloadMode::Whole => file.download(&path).await?,
+        DownloadMode::Range(start, end) => file.download_range(&path, start, end).await?,
+        DownloadMode::Parallel => file.download_parallel(&path).await?,
+    }
+    println!("File {} downloaded to {}", id, path.to_str().unwrap());
+    Ok(())
+}
+
+async fn download_file_to_stdout(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    // stdout carries the content, so progress goes to stderr
+    eprintln!("Downloading file {}...", id);
+    ctx.client
+        .file(id)
+        .download_to(&mut tokio::io::stdout())
+        .await?;
+    eprintln!("File {} downloaded", id);
+    Ok(())
+}
+
+async fn download_zip(
+    ctx: &mut Context,
+    zip: NewZipDownload,
+    path: &Path,
+    policy: ExistingFilePolicy,
+) -> Result<(), SDKError> {
+    // When stdout carries the archive, progress goes to stderr
+    let to_stdout = path == Path::new("-");
+    let path = if to_stdout {
+        None
+    } else {
+        match policy.destination(path).await? {
+            Destination::Download(path) => Some(path),
+            Destination::Skip(path) => {
+                println!("Zip archive skipped, {} already exists", path.display());
+                return Ok(());
+            }
+        }
+    };
+
+    let zip = ctx.client.create_zip_download(zip).await?;
+    let mut operation = ctx.client.zip_download(&zip);
+    let (status, destination) = match path {
+        None => {
+            eprintln!("Downloading zip archive...");
+            let status = operation.download_to(&mut tokio::io::stdout()).await?;
+            (status, String::new())
+        }
+        Some(path) => {
+            println!("Downloading zip archive...");
+            let status = operation.download(&path).await?;
+            (status, format!(" to {}", path.display()))
+        }
+    };
+
+    let mut report = format!(
+        "Downloaded {} of {} files{}",
+        status.downloaded_file_count(),
+        status.total_file_count(),
+        destination
+    );
+    if status.skipped_file_count() > 0 || status.skipped_folder_count() > 0 {
+        report = format!(
+            "{}, skipped {} files and {} folders",
+            report,
+            status.skipped_file_count(),
+            status.skipped_folder_count()
+        );
+    }
+    if to_stdout {
+        eprintln!("{}", report);
+    } else {
+        println!("{}", report);
+    }
+    Ok(())
+}
+
+async fn upload_file_version(
+    ctx: &mut Context,
+    id: &str,
+    path: &Path,
+    if_match: Option<&str>,
+    name: Option<&str>,
+) -> Result<(), SDKError> {
+    let file = ctx
+        .client
+        .file(id)
+        .upload_version(path, if_match, name)
+        .await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn copy_file(
+    ctx: &mut Context,
+    id: &str,
+    folder_id: &str,
+    name: Option<&str>,
+    version: Option<&str>,
+    rename: bool,
+) -> Result<(), SDKError> {
+    let file = ctx
+        .client
+        .file(id)
+        .copy(folder_id, name, version, rename)
+        .await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn move_file(ctx: &mut Context, id: &str, folder_id: &str) -> Result<(), SDKError> {
+    let file = ctx.client.file(id).move_to(folder_id).await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn lock_file(
+    ctx: &mut Context,
+    id: &str,
+    expires_at: Option<DateTime<FixedOffset>>,
+    prevent_download: bool,
+) -> Result<(), SDKError> {
+    let file = ctx
+        .client
+        .file(id)
+        .lock(expires_at, prevent_download)
+        .await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn unlock_file(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let file = ctx.client.file(id).unlock().await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn list_comments(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let comments = ctx.client.file(id).comments().await?;
+    ctx.output(Comment::threads(comments));
+    Ok(())
+}
+
+async fn list_representations(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let representations = ctx.client.file(id).representations(None).await?;
+    ctx.output(representations);
+    Ok(())
+}
+
+async fn get_representation(
+    ctx: &mut Context,
+    id: &str,
+    rep_type: &str,
+    size: Option<&str>,
+    asset_path: &str,
+    path: &Path,
+) -> Result<(), SDKError> {
+    println!("Generating {} representation of file {}...", rep_type, id);
+    let mut file = ctx.client.file(id);
+    let representation = file.representation(rep_type, size).await?;
+    file.download_representation(&representation, asset_path, path)
+        .await?;
+    println!("Representation downloaded to {}", path.to_str().unwrap());
+    Ok(())
+}
+
+async fn list_file_versions(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let versions = ctx.client.file_versions(id).list().await?;
+    ctx.output(versions);
+    Ok(())
+}
+
+async fn download_file_version(
+    ctx: &mut Context,
+    id: &str,
+    version_id: &str,
+    path: &Path,
+) -> Result<(), SDKError> {
+    let path = match ctx
+        .client
+        .file(id)
+        .destination(path, ExistingFilePolicy::Fail)
+        .await?
+    {
+        Destination::Download(path) => path,
+        Destination::Skip(path) => {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            )
+            .into())
+        }
+    };
+    println!("Downloading version {} of file {}...", version_id, id);
+    ctx.client
+        .file_versions(id)
+        .download(version_id, &path)
+        .await?;
+    println!(
+        "Version {} of file {} downloaded to {}",
+        version_id,
+        id,
+        path.to_str().unwrap()
+    );
+    Ok(())
+}
+
+async fn promote_file_version(
+    ctx: &mut Context,
+    id: &str,
+    version_id: &str,
+) -> Result<(), SDKError> {
+    let version = ctx.client.file_versions(id).promote(version_id).await?;
+    ctx.output(version);
+    Ok(())
+}
+
+async fn verify_file(ctx: &mut Context, id: &str, path: &Path) -> Result<(), SDKError> {
+    ctx.client.file(id).verify(path).await?;
+    println!("File {} matches {}", id, path.to_str().unwrap());
+    Ok(())
+}
 
 async fn delete_file(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
     ctx.client.file(id).delete().await?;
@@ -226,6 +2018,37 @@ async fn upload_file(ctx: &mut Context, path: &Path, folder_id: &str) -> Result<
     Ok(())
 }
 
+async fn upload_stdin(
+    ctx: &mut Context,
+    name: &str,
+    folder_id: &str,
+    size: Option<u64>,
+) -> Result<(), SDKError> {
+    let file = ctx
+        .client
+        .upload_reader(tokio::io::stdin(), name, folder_id, size)
+        .await?;
+    ctx.output(file);
+    Ok(())
+}
+
+async fn preflight_upload(ctx: &mut Context, path: &Path, folder_id: &str) -> Result<(), SDKError> {
+    let name = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| SDKError::InvalidFileName(path.to_owned()))?;
+    let size = tokio::fs::metadata(path).await?.len();
+    let preflight = ctx.client.preflight_upload(name, folder_id, size).await?;
+    ctx.output(preflight);
+    Ok(())
+}
+
+async fn resume_upload(ctx: &mut Context, path: &Path) -> Result<(), SDKError> {
+    let file = ctx.client.resume_upload(path).await?;
+    ctx.output(file);
+    Ok(())
+}
+
 async fn get_folder(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
     let folder = ctx.client.folder(id).get().await?;
     ctx.output(folder);
@@ -238,8 +2061,750 @@ async fn get_folder_items(ctx: &mut Context, id: &str, limit: u32) -> Result<(),
     Ok(())
 }
 
+async fn copy_folder(
+    ctx: &mut Context,
+    id: &str,
+    folder_id: &str,
+    name: Option<&str>,
+    rename: bool,
+) -> Result<(), SDKError> {
+    let folder = ctx.client.folder(id).copy(folder_id, name, rename).await?;
+    ctx.output(folder);
+    Ok(())
+}
+
+async fn move_folder(ctx: &mut Context, id: &str, folder_id: &str) -> Result<(), SDKError> {
+    let folder = ctx.client.folder(id).move_to(folder_id).await?;
+    ctx.output(folder);
+    Ok(())
+}
+
+async fn get_comment(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let comment = ctx.client.comment(id).get().await?;
+    ctx.output(comment);
+    Ok(())
+}
+
+async fn create_comment(ctx: &mut Context, file_id: &str, message: &str) -> Result<(), SDKError> {
+    let comment = ctx.client.create_comment(file_id, message).await?;
+    ctx.output(comment);
+    Ok(())
+}
+
+async fn reply_to_comment(ctx: &mut Context, id: &str, message: &str) -> Result<(), SDKError> {
+    let comment = ctx.client.comment(id).reply(message).await?;
+    ctx.output(comment);
+    Ok(())
+}
+
+async fn update_comment(ctx: &mut Context, id: &str, message: &str) -> Result<(), SDKError> {
+    let comment = ctx.client.comment(id).update(message).await?;
+    ctx.output(comment);
+    Ok(())
+}
+
+async fn delete_comment(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    ctx.client.comment(id).delete().await?;
+    println!("Comment {} deleted", id);
+    Ok(())
+}
+
+async fn get_task(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let task = ctx.client.task(id).get().await?;
+    ctx.output(task);
+    Ok(())
+}
+
+async fn create_task(ctx: &mut Context, task: NewTask) -> Result<(), SDKError> {
+    let task = ctx.client.create_task(task).await?;
+    ctx.output(task);
+    Ok(())
+}
+
+async fn list_tasks(ctx: &mut Context, file_id: &str) -> Result<(), SDKError> {
+    let tasks = ctx.client.file(file_id).tasks().await?;
+    ctx.output(tasks);
+    Ok(())
+}
+
+async fn assign_task(
+    ctx: &mut Context,
+    id: &str,
+    user_id: Option<&str>,
+    login: Option<&str>,
+) -> Result<(), SDKError> {
+    let mut task = ctx.client.task(id);
+    let assignment = match (user_id, login) {
+        (Some(user_id), _) => task.assign_to_user(user_id).await?,
+        (None, Some(login)) => task.assign_to_login(login).await?,
+        (None, None) => unreachable!("clap requires a user ID or login"),
+    };
+    ctx.output(assignment);
+    Ok(())
+}
+
+async fn list_task_assignments(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let assignments = ctx.client.task(id).assignments().await?;
+    ctx.output(assignments);
+    Ok(())
+}
+
+async fn resolve_task_assignment(
+    ctx: &mut Context,
+    id: &str,
+    state: ResolutionState,
+    message: Option<&str>,
+) -> Result<(), SDKError> {
+    let assignment = ctx
+        .client
+        .task_assignment(id)
+        .resolve(state, message)
+        .await?;
+    ctx.output(assignment);
+    Ok(())
+}
+
+async fn delete_task(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    ctx.client.task(id).delete().await?;
+    println!("Task {} deleted", id);
+    Ok(())
+}
+
+async fn metadata_action(
+    ctx: &mut Context,
+    target: MetadataTarget<'_>,
+    matches: &ArgMatches<'_>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let template = |matches: &ArgMatches<'_>| {
+        let scope = matches.value_of("scope").unwrap().to_owned();
+        (scope, matches.value_of("template").unwrap().to_owned())
+    };
+
+    if let Some(matches) = matches.subcommand_matches("get") {
+        let (scope, template) = template(matches);
+        get_metadata(ctx, &target, &scope, &template).await?;
+    } else if let Some(matches) = matches.subcommand_matches("create") {
+        let (scope, template) = template(matches);
+        let mut fields: Map<String, Value> = match matches.value_of("data") {
+            Some(path) => serde_json::from_slice(&tokio::fs::read(path).await?)?,
+            None => Map::new(),
+        };
+        for field in matches.values_of("fields").into_iter().flatten() {
+            let (field, value) = parse_field(field)?;
+            fields.insert(field.to_owned(), value);
+        }
+        create_metadata(ctx, &target, &scope, &template, fields).await?;
+    } else if let Some(matches) = matches.subcommand_matches("update") {
+        let (scope, template) = template(matches);
+        let mut updates = MetadataUpdates::new();
+        if let Some(path) = matches.value_of("data") {
+            let patch: Vec<MetadataPatch> = serde_json::from_slice(&tokio::fs::read(path).await?)?;
+            for operation in patch {
+                updates = updates.operation(operation);
+            }
+        }
+        for field in matches.values_of("fields").into_iter().flatten() {
+            let (field, value) = parse_field(field)?;
+            updates = updates.add(field, value);
+        }
+        for field in matches.values_of("remove").into_iter().flatten() {
+            updates = updates.remove(field);
+        }
+        update_metadata(ctx, &target, &scope, &template, updates).await?;
+    } else if let Some(matches) = matches.subcommand_matches("delete") {
+        let (scope, template) = template(matches);
+        delete_metadata(ctx, &target, &scope, &template).await?;
+    } else {
+        list_metadata(ctx, &target).await?;
+    }
+    Ok(())
+}
+
+async fn list_metadata(ctx: &mut Context, target: &MetadataTarget<'_>) -> Result<(), SDKError> {
+    let instances = target.operation(&mut ctx.client).list().await?;
+    ctx.output_metadata(&instances, &instances);
+    Ok(())
+}
+
+async fn get_metadata(
+    ctx: &mut Context,
+    target: &MetadataTarget<'_>,
+    scope: &str,
+    template: &str,
+) -> Result<(), SDKError> {
+    let metadata = target
+        .operation(&mut ctx.client)
+        .get(scope, template)
+        .await?;
+    ctx.output_metadata(&metadata, slice::from_ref(&metadata));
+    Ok(())
+}
+
+async fn create_metadata(
+    ctx: &mut Context,
+    target: &MetadataTarget<'_>,
+    scope: &str,
+    template: &str,
+    fields: Map<String, Value>,
+) -> Result<(), SDKError> {
+    let metadata = target
+        .operation(&mut ctx.client)
+        .create(scope, template, fields)
+        .await?;
+    ctx.output_metadata(&metadata, slice::from_ref(&metadata));
+    Ok(())
+}
+
+async fn update_metadata(
+    ctx: &mut Context,
+    target: &MetadataTarget<'_>,
+    scope: &str,
+    template: &str,
+    updates: MetadataUpdates,
+) -> Result<(), SDKError> {
+    let metadata = target
+        .operation(&mut ctx.client)
+        .update(scope, template, updates)
+        .await?;
+    ctx.output_metadata(&metadata, slice::from_ref(&metadata));
+    Ok(())
+}
+
+async fn delete_metadata(
+    ctx: &mut Context,
+    target: &MetadataTarget<'_>,
+    scope: &str,
+    template: &str,
+) -> Result<(), SDKError> {
+    target
+        .operation(&mut ctx.client)
+        .delete(scope, template)
+        .await?;
+    println!("Metadata {}/{} deleted", scope, template);
+    Ok(())
+}
+
+async fn query_metadata(ctx: &mut Context, query: MetadataQuery) -> Result<(), SDKError> {
+    // Items are printed as each page arrives rather than all at the end
+    let mut items = Box::pin(ctx.client.execute_metadata_query(query));
+    while let Some(item) = items.try_next().await? {
+        match ctx.fmt {
+            OutputFormat::Debug => println!("{:?}", item),
+            OutputFormat::JSON => println!("{}", &serde_json::to_string(&item).unwrap()),
+        }
+    }
+    Ok(())
+}
+
+/// Writes a CSV row for each file with the template, with a column for each of
+/// the template's fields after the file's ID and name.
+async fn export_metadata(
+    ctx: &mut Context,
+    template: &str,
+    folder_id: &str,
+    recursive: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (scope, template_key) = split_template(template)?;
+    let schema = ctx
+        .client
+        .metadata_template(scope, template_key)
+        .get()
+        .await?;
+    let fields = schema.fields().unwrap_or(&[]);
+
+    let mut writer = csv::Writer::from_writer(std::io::stdout());
+    let mut header = vec!["id", "name"];
+    header.extend(fields.iter().map(|field| field.key()));
+    writer.write_record(&header)?;
+
+    let metadata_field = format!("metadata.{}", template);
+    let fields_wanted = ["name", &metadata_field];
+    let mut items = if recursive {
+        let query = MetadataQuery::new(template, folder_id).fields(&fields_wanted);
+        ctx.client.execute_metadata_query(query).boxed_local()
+    } else {
+        let items = ctx
+            .client
+            .folder(folder_id)
+            .get_all_items_with_fields(&fields_wanted)
+            .await?;
+        stream::iter(items.into_iter().map(Ok)).boxed_local()
+    };
+    while let Some(item) = items.try_next().await? {
+        let file = match item {
+            Item::File(file) => file,
+            _ => continue,
+        };
+        let instance = match file
+            .extra()
+            .get("metadata")
+            .and_then(|metadata| metadata.get(scope))
+            .and_then(|metadata| metadata.get(template_key))
+        {
+            Some(instance) => instance,
+            // Files in the folder without the template aren't exported
+            None => continue,
+        };
+        let mut record = vec![file.id().to_owned(), file.name().unwrap_or("").to_owned()];
+        for field in fields {
+            let value = instance.get(field.key());
+            record.push(value.map(csv_value).unwrap_or_default());
+        }
+        writer.write_record(&record)?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Applies the template to the file on each row of a CSV, creating the
+/// instance or updating the fields with a value in the row and removing the
+/// fields left empty. Without a template, the enterprise template with all of
+/// the CSV's columns is used. A row that fails is reported and the rest are
+/// still imported.
+async fn import_metadata(
+    ctx: &mut Context,
+    path: &Path,
+    template: Option<&str>,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let headers = reader.headers()?.clone();
+    if !headers
+        .iter()
+        .any(|column| column == "id" || column == "path")
+    {
+        return Err("the CSV needs an id or path column".into());
+    }
+    let field_columns: Vec<&str> = headers
+        .iter()
+        .filter(|column| !["id", "path", "name"].contains(column))
+        .collect();
+
+    let schema = match template {
+        Some(template) => {
+            let (scope, template_key) = split_template(template)?;
+            ctx.client
+                .metadata_template(scope, template_key)
+                .get()
+                .await?
+        }
+        None => infer_metadata_template(ctx, &field_columns).await?,
+    };
+    let (scope, template_key) = (schema.scope(), schema.template_key());
+    let fields = schema.fields().unwrap_or(&[]);
+    let columns = headers
+        .iter()
+        .map(|column| match column {
+            "id" | "path" | "name" => Ok(None),
+            column => fields
+                .iter()
+                .find(|field| field.key() == column)
+                .map(Some)
+                .ok_or_else(|| format!("{} is not a field of {}.{}", column, scope, template_key)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Rows often share folders, so each folder on their paths is listed once
+    let mut listings = FolderListings::new();
+    let mut failed = 0;
+    for (index, record) in reader.records().enumerate() {
+        // Row numbers match the lines of the file, after the header
+        let row = index + 2;
+        let result = match record {
+            Ok(record) => {
+                import_metadata_row(
+                    ctx,
+                    &mut listings,
+                    &headers,
+                    &columns,
+                    &record,
+                    &schema,
+                    dry_run,
+                )
+                .await
+            }
+            Err(error) => Err(error.into()),
+        };
+        match result {
+            Ok(message) => println!("Row {}: {}", row, message),
+            Err(error) => {
+                failed += 1;
+                eprintln!("Row {}: {}", row, error);
+            }
+        }
+    }
+
+    match failed {
+        0 => Ok(()),
+        failed => Err(format!("{} rows could not be imported", failed).into()),
+    }
+}
+
+/// Finds the one enterprise template that has a field for each of `columns`.
+async fn infer_metadata_template(
+    ctx: &mut Context,
+    columns: &[&str],
+) -> Result<MetadataTemplate, Box<dyn std::error::Error>> {
+    let mut templates: Vec<MetadataTemplate> = ctx
+        .client
+        .metadata_templates()
+        .list("enterprise")
+        .await?
+        .into_iter()
+        .filter(|template| {
+            let fields = template.fields().unwrap_or(&[]);
+            columns
+                .iter()
+                .all(|column| fields.iter().any(|field| field.key() == *column))
+        })
+        .collect();
+
+    match templates.len() {
+        1 => Ok(templates.remove(0)),
+        0 => Err("no enterprise template has all of the CSV's columns; use --template".into()),
+        _ => {
+            let names: Vec<String> = templates
+                .iter()
+                .map(|template| format!("{}.{}", template.scope(), template.template_key()))
+                .collect();
+            Err(format!(
+                "the CSV's columns match several templates ({}); use --template",
+                names.join(", ")
+            )
+            .into())
+        }
+    }
+}
+
+async fn import_metadata_row(
+    ctx: &mut Context,
+    listings: &mut FolderListings,
+    headers: &csv::StringRecord,
+    columns: &[Option<&MetadataField>],
+    record: &csv::StringRecord,
+    template: &MetadataTemplate,
+    dry_run: bool,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let (scope, template_key) = (template.scope(), template.template_key());
+    let cell = |name| {
+        headers
+            .iter()
+            .position(|column| column == name)
+            .and_then(|index| record.get(index))
+            .filter(|value| !value.is_empty())
+    };
+    let file_id = match (cell("id"), cell("path")) {
+        (Some(id), _) => id.to_owned(),
+        (None, Some(path)) => match ctx
+            .client
+            .folder("0")
+            .item_at_cached(path, listings)
+            .await?
+        {
+            Item::File(file) => file.id().to_owned(),
+            _ => return Err(format!("{} is not a file", path).into()),
+        },
+        (None, None) => return Err("no file id or path".into()),
+    };
+
+    // Empty cells are sent as nulls, which remove the field
+    let mut values = Map::new();
+    for (field, value) in columns.iter().zip(record.iter()) {
+        if let Some(field) = field {
+            let value = match value {
+                "" => Value::Null,
+                value => parse_csv_value(field, value)?,
+            };
+            values.insert(field.key().to_owned(), value);
+        }
+    }
+
+    let mut metadata = ctx.client.file_metadata(&file_id);
+    if !dry_run {
+        metadata.upsert(scope, template_key, values).await?;
+        return Ok(format!("file {} updated", file_id));
+    }
+    let (action, changes) = match metadata.get(scope, template_key).await {
+        Ok(existing) => (
+            "update",
+            serde_json::to_string(&MetadataUpdates::setting(&existing, values))?,
+        ),
+        Err(SDKError::APIError { response }) if response.status() == 404 => {
+            values.retain(|_, value| !value.is_null());
+            ("create", serde_json::to_string(&values)?)
+        }
+        Err(error) => return Err(error.into()),
+    };
+    Ok(format!(
+        "would {} metadata on file {} with {}",
+        action, file_id, changes
+    ))
+}
+
+async fn list_metadata_templates(ctx: &mut Context, scopes: &[&str]) -> Result<(), SDKError> {
+    let mut templates = Vec::new();
+    for scope in scopes {
+        templates.extend(ctx.client.metadata_templates().list(scope).await?);
+    }
+    ctx.output(templates);
+    Ok(())
+}
+
+async fn get_metadata_template(
+    ctx: &mut Context,
+    scope: &str,
+    template_key: &str,
+) -> Result<(), SDKError> {
+    let template = ctx
+        .client
+        .metadata_template(scope, template_key)
+        .get()
+        .await?;
+    ctx.output(template);
+    Ok(())
+}
+
+async fn create_metadata_template(
+    ctx: &mut Context,
+    template: NewMetadataTemplate,
+) -> Result<(), SDKError> {
+    let template = ctx.client.create_metadata_template(template).await?;
+    ctx.output(template);
+    Ok(())
+}
+
+async fn update_metadata_template(
+    ctx: &mut Context,
+    scope: &str,
+    template_key: &str,
+    updates: MetadataTemplateUpdates,
+) -> Result<(), SDKError> {
+    let template = ctx
+        .client
+        .metadata_template(scope, template_key)
+        .update(updates)
+        .await?;
+    ctx.output(template);
+    Ok(())
+}
+
+async fn delete_metadata_template(
+    ctx: &mut Context,
+    scope: &str,
+    template_key: &str,
+) -> Result<(), SDKError> {
+    ctx.client
+        .metadata_template(scope, template_key)
+        .delete()
+        .await?;
+    println!("Metadata template {}/{} deleted", scope, template_key);
+    Ok(())
+}
+
+async fn get_web_link(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    let web_link = ctx.client.web_link(id).get().await?;
+    ctx.output(web_link);
+    Ok(())
+}
+
+async fn create_web_link(ctx: &mut Context, web_link: NewWebLink) -> Result<(), SDKError> {
+    let web_link = ctx.client.create_web_link(web_link).await?;
+    ctx.output(web_link);
+    Ok(())
+}
+
+async fn update_web_link(
+    ctx: &mut Context,
+    id: &str,
+    updates: WebLinkUpdates,
+) -> Result<(), SDKError> {
+    let web_link = ctx.client.web_link(id).update(updates).await?;
+    ctx.output(web_link);
+    Ok(())
+}
+
+async fn move_web_link(ctx: &mut Context, id: &str, folder_id: &str) -> Result<(), SDKError> {
+    let web_link = ctx.client.web_link(id).move_to(folder_id).await?;
+    ctx.output(web_link);
+    Ok(())
+}
+
+async fn delete_web_link(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
+    ctx.client.web_link(id).delete().await?;
+    println!("Web link {} deleted", id);
+    Ok(())
+}
+
 async fn get_user(ctx: &mut Context, id: &str) -> Result<(), SDKError> {
     let user = ctx.client.user(id).get().await?;
     ctx.output(user);
     Ok(())
 }
+
+async fn list_users(ctx: &mut Context, query: UserQuery) -> Result<(), SDKError> {
+    let users = ctx.client.users().list(query).await?;
+    ctx.output(users);
+    Ok(())
+}
+
+/// What to do about an existing file, from the `--overwrite`, `--skip-existing`,
+/// `--rename` and `--if-newer` flags.
+fn existing_file_policy(matches: &ArgMatches<'_>) -> ExistingFilePolicy {
+    if matches.is_present("overwrite") {
+        ExistingFilePolicy::Overwrite
+    } else if matches.is_present("skipExisting") {
+        ExistingFilePolicy::Skip
+    } else if matches.is_present("rename") {
+        ExistingFilePolicy::Rename
+    } else if matches.is_present("ifNewer") {
+        ExistingFilePolicy::IfNewer
+    } else {
+        ExistingFilePolicy::Fail
+    }
+}
+
+/// Parses a byte range like `0-1023`, or `1024-` for everything from byte 1024.
+fn parse_range(range: &str) -> Result<(u64, Option<u64>), std::num::ParseIntError> {
+    let mut bounds = range.splitn(2, '-');
+    let start = bounds.next().unwrap().parse()?;
+    let end = match bounds.next() {
+        Some("") | None => None,
+        Some(end) => Some(end.parse()?),
+    };
+    Ok((start, end))
+}
+
+/// Reads a JSON file, or a YAML file if it has a `.yaml` or `.yml` extension.
+async fn read_definition<T: DeserializeOwned>(
+    path: &Path,
+) -> Result<T, Box<dyn std::error::Error>> {
+    let content = tokio::fs::read(path).await?;
+    match path.extension().and_then(|extension| extension.to_str()) {
+        Some("yaml") | Some("yml") => Ok(serde_yaml::from_slice(&content)?),
+        _ => Ok(serde_json::from_slice(&content)?),
+    }
+}
+
+/// Splits a `FIELD=VALUE` argument, where the value is a string, or a
+/// `FIELD:=JSON` argument for values of other types.
+fn parse_field(field: &str) -> Result<(&str, Value), String> {
+    let invalid = || format!("invalid field, expected FIELD=VALUE: {}", field);
+    let equals = field.find('=').ok_or_else(invalid)?;
+    match field[..equals].strip_suffix(':') {
+        Some(name) => {
+            let value = serde_json::from_str(&field[equals + 1..]).map_err(|_| invalid())?;
+            Ok((name, value))
+        }
+        None => Ok((
+            &field[..equals],
+            Value::String(field[equals + 1..].to_owned()),
+        )),
+    }
+}
+
+/// Splits a `NAME=VALUE` query parameter. Values that parse as JSON, like
+/// numbers, are sent as JSON; anything else is sent as a string.
+fn parse_query_param(param: &str) -> Result<(&str, Value), String> {
+    let (name, value) = param
+        .split_once('=')
+        .ok_or_else(|| format!("invalid parameter, expected NAME=VALUE: {}", param))?;
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_owned()));
+    Ok((name, value))
+}
+
+/// Splits a template given as `SCOPE.TEMPLATE`.
+fn split_template(template: &str) -> Result<(&str, &str), String> {
+    template
+        .split_once('.')
+        .ok_or_else(|| format!("invalid template, expected SCOPE.TEMPLATE: {}", template))
+}
+
+/// A metadata value as it's written in a CSV cell. The options of a
+/// multi-select field are separated by `|`.
+fn csv_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Array(values) => values.iter().map(csv_value).collect::<Vec<_>>().join("|"),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+fn parse_csv_value(field: &MetadataField, value: &str) -> Result<Value, String> {
+    match field.field_type() {
+        MetadataFieldType::Float => value
+            .parse::<f64>()
+            .map(Value::from)
+            .map_err(|_| format!("{} is not a number: {}", field.key(), value)),
+        MetadataFieldType::MultiSelect => Ok(value.split('|').map(Value::from).collect()),
+        _ => Ok(Value::String(value.to_owned())),
+    }
+}
+
+/// Prints metadata instances with a row for each of their fields.
+fn print_metadata_table(instances: &[Metadata]) {
+    let mut rows = vec![(
+        "TEMPLATE".to_owned(),
+        "FIELD".to_owned(),
+        "VALUE".to_owned(),
+    )];
+    for instance in instances {
+        let template = format!("{}/{}", instance.scope(), instance.template());
+        for (field, value) in instance.fields() {
+            rows.push((template.clone(), field.clone(), metadata_value(value)));
+        }
+    }
+
+    let template_width = rows.iter().map(|row| row.0.len()).max().unwrap_or(0);
+    let field_width = rows.iter().map(|row| row.1.len()).max().unwrap_or(0);
+    for (template, field, value) in rows {
+        println!(
+            "{:tw$}  {:fw$}  {}",
+            template,
+            field,
+            value,
+            tw = template_width,
+            fw = field_width
+        );
+    }
+}
+
+fn metadata_value(value: &Value) -> String {
+    match value {
+        Value::String(value) => value.clone(),
+        Value::Array(values) => values
+            .iter()
+            .map(metadata_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+/// Parses a duration like `90s`, `30m`, `2h` or `7d`. The amount must be
+/// more than zero.
+fn parse_duration(duration: &str) -> Result<Duration, String> {
+    let invalid = || format!("invalid duration: {}", duration);
+    let unit = duration.chars().last().ok_or_else(invalid)?;
+    let amount: i64 = duration[..duration.len() - unit.len_utf8()]
+        .parse()
+        .ok()
+        .filter(|amount| *amount > 0)
+        .ok_or_else(invalid)?;
+    match unit {
+        's' => Duration::try_seconds(amount),
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        _ => None,
+    }
+    .ok_or_else(invalid)
+}
+
+/// The time `duration` (as taken by [`parse_duration`]) from now.
+fn from_now(duration: &str) -> Result<DateTime<Utc>, String> {
+    Utc::now()
+        .checked_add_signed(parse_duration(duration)?)
+        .ok_or_else(|| format!("{} from now is too far off", duration))
+}
@@ -0,0 +1,23 @@
+use super::SDKError;
+use sha1::{Digest, Sha1};
+use std::path::Path;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+
+/// Returns the hex-encoded SHA1 digest of the file at `path`, the same format
+/// Box uses for `File::sha1`.
+pub async fn sha1_file(path: &Path) -> Result<String, SDKError> {
+    let mut file = fs::File::open(path).await?;
+    let mut hasher = Sha1::new();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
@@ -4,17 +4,49 @@
 use serde::Serialize;
 
 use super::auth::{Auth, SingleTokenAuth};
-use super::models::{Collection, File};
-use super::operations::{FileOperation, FolderOperation, UserOperation};
-use super::{Body, HTTPMethod, MultipartBody, NetworkAgent, Request, Response, SDKError};
+use super::models::{
+    ClientError, Collection, Comment, File, Item, MarkerCollection, MetadataTemplate, Task,
+    UploadPreflight, UploadSession, WebLink, ZipDownload,
+};
+use super::network::upload_url;
+use super::operations::comment::{message_field, COMMENT_FIELDS};
+use super::operations::{
+    CommentOperation, FileOperation, FileVersionOperation, FolderOperation, MetadataOperation,
+    MetadataQuery, MetadataTemplateOperation, MetadataTemplatesOperation, NewMetadataTemplate,
+    NewTask, NewWebLink, NewZipDownload, TaskAssignmentOperation, TaskOperation,
+    UploadSessionOperation, UserOperation, UsersOperation, WebLinkOperation, ZipDownloadOperation,
+};
+use super::{
+    sha1_file, Body, HTTPMethod, MultipartBody, NetworkAgent, Request, Response, SDKError,
+    UploadState,
+};
+use bytes::Bytes;
+use futures::future;
+use futures::stream::{self, Stream, TryStream, TryStreamExt};
+use http::StatusCode;
 use serde_json::json;
+use sha1::{Digest, Sha1};
 use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tokio::fs;
+use tokio::io::{self as io, AsyncRead, AsyncReadExt, AsyncWriteExt};
 use tokio_util::codec::{BytesCodec, FramedRead};
 
+const DEFAULT_CHUNKED_UPLOAD_THRESHOLD: u64 = 50 * 1024 * 1024;
+const DEFAULT_UPLOAD_CONCURRENCY: usize = 4;
+const DEFAULT_DOWNLOAD_PART_SIZE: u64 = 16 * 1024 * 1024;
+const DEFAULT_DOWNLOAD_CONCURRENCY: usize = 4;
+
+/// Tells apart the temporary files of uploads from the same process.
+static TEMP_FILE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
 pub struct Client {
-    auth: Box<dyn Auth>,
+    auth: Box<dyn Auth + Send + Sync>,
     network: NetworkAgent,
+    chunked_upload_threshold: u64,
+    upload_concurrency: usize,
+    download_part_size: u64,
+    download_concurrency: usize,
 }
 
 impl Client {
@@ -22,10 +54,57 @@ impl Client {
         Client {
             auth: Box::from(SingleTokenAuth::new(token)),
             network: NetworkAgent::new(),
+            chunked_upload_threshold: DEFAULT_CHUNKED_UPLOAD_THRESHOLD,
+            upload_concurrency: DEFAULT_UPLOAD_CONCURRENCY,
+            download_part_size: DEFAULT_DOWNLOAD_PART_SIZE,
+            download_concurrency: DEFAULT_DOWNLOAD_CONCURRENCY,
         }
     }
 
-    async fn make_request(&mut self, request: Request) -> Result<Response, SDKError> {
+    pub fn with_auth<A: Auth + Send + Sync + 'static>(auth: A) -> Client {
+        Client {
+            auth: Box::from(auth),
+            network: NetworkAgent::new(),
+            chunked_upload_threshold: DEFAULT_CHUNKED_UPLOAD_THRESHOLD,
+            upload_concurrency: DEFAULT_UPLOAD_CONCURRENCY,
+            download_part_size: DEFAULT_DOWNLOAD_PART_SIZE,
+            download_concurrency: DEFAULT_DOWNLOAD_CONCURRENCY,
+        }
+    }
+
+    /// Files larger than `threshold` bytes are uploaded through an upload session.
+    pub fn with_chunked_upload_threshold(mut self, threshold: u64) -> Client {
+        self.chunked_upload_threshold = threshold;
+        self
+    }
+
+    /// Sets how many parts of a chunked upload are sent at the same time.
+    pub fn with_upload_concurrency(mut self, concurrency: usize) -> Client {
+        self.upload_concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sets how many bytes each request of a parallel download fetches.
+    pub fn with_download_part_size(mut self, part_size: u64) -> Client {
+        self.download_part_size = part_size.max(1);
+        self
+    }
+
+    /// Sets how many requests of a parallel download are made at the same time.
+    pub fn with_download_concurrency(mut self, concurrency: usize) -> Client {
+        self.download_concurrency = concurrency.max(1);
+        self
+    }
+
+    pub(crate) fn network(&self) -> &NetworkAgent {
+        &self.network
+    }
+
+    pub(crate) async fn access_token(&mut self) -> Result<String, SDKError> {
+        Ok(self.auth.token().await?.as_str().to_owned())
+    }
+
+    pub(crate) async fn send(&mut self, request: Request) -> Result<Response, SDKError> {
         let mut request = request;
 
         let access_token = &self.auth.token().await?;
@@ -38,7 +117,15 @@ impl Client {
 
     pub async fn get(&mut self, url: &str) -> Result<Response, SDKError> {
         let request = self.network.start_request(HTTPMethod::GET, url);
-        self.make_request(request).await
+        self.send(request).await
+    }
+
+    pub async fn post<T: Serialize>(&mut self, url: &str, body: T) -> Result<Response, SDKError> {
+        let request = self
+            .network
+            .start_request(HTTPMethod::POST, url)
+            .with_body(Body::JSON(serde_json::to_value(body)?));
+        self.send(request).await
     }
 
     pub async fn put<T: Serialize>(&mut self, url: &str, body: T) -> Result<Response, SDKError> {
@@ -46,15 +133,27 @@ impl Client {
             .network
             .start_request(HTTPMethod::PUT, url)
             .with_body(Body::JSON(serde_json::to_value(body)?));
-        self.make_request(request).await
+        self.send(request).await
+    }
+
+    pub async fn options<T: Serialize>(
+        &mut self,
+        url: &str,
+        body: T,
+    ) -> Result<Response, SDKError> {
+        let request = self
+            .network
+            .start_request(HTTPMethod::OPTIONS, url)
+            .with_body(Body::JSON(serde_json::to_value(body)?));
+        self.send(request).await
     }
 
     pub async fn delete(&mut self, url: &str) -> Result<Response, SDKError> {
         let request = self.network.start_request(HTTPMethod::DELETE, url);
-        self.make_request(request).await
+        self.send(request).await
     }
 
-    pub async fn multipart_upload<'a>(
+    pub async fn multipart_upload(
         &mut self,
         url: &str,
         body: MultipartBody,
@@ -63,28 +162,179 @@ impl Client {
             .network
             .start_request(HTTPMethod::POST, url)
             .with_body(Body::Multipart(body));
-        self.make_request(request).await
+        self.send(request).await
+    }
+
+    pub fn comment<'a>(&'a mut self, id: &'a str) -> CommentOperation<'a> {
+        CommentOperation::new(id, self)
     }
 
     pub fn file<'a>(&'a mut self, id: &'a str) -> FileOperation<'a> {
         FileOperation::new(id, self)
     }
 
+    pub fn file_versions<'a>(&'a mut self, file_id: &'a str) -> FileVersionOperation<'a> {
+        FileVersionOperation::new(file_id, self)
+    }
+
+    pub fn file_metadata<'a>(&'a mut self, file_id: &'a str) -> MetadataOperation<'a> {
+        MetadataOperation::for_file(file_id, self)
+    }
+
     pub fn folder<'a>(&'a mut self, id: &'a str) -> FolderOperation<'a> {
         FolderOperation::new(id, self)
     }
 
+    pub fn folder_metadata<'a>(&'a mut self, folder_id: &'a str) -> MetadataOperation<'a> {
+        MetadataOperation::for_folder(folder_id, self)
+    }
+
+    pub fn metadata_template<'a>(
+        &'a mut self,
+        scope: &'a str,
+        template_key: &'a str,
+    ) -> MetadataTemplateOperation<'a> {
+        MetadataTemplateOperation::new(scope, template_key, self)
+    }
+
+    pub fn metadata_templates(&mut self) -> MetadataTemplatesOperation<'_> {
+        MetadataTemplatesOperation::new(self)
+    }
+
     pub fn user<'a>(&'a mut self, id: &'a str) -> UserOperation<'a> {
         UserOperation::new(id, self)
     }
 
+    pub fn web_link<'a>(&'a mut self, id: &'a str) -> WebLinkOperation<'a> {
+        WebLinkOperation::new(id, self)
+    }
+
+    pub fn task<'a>(&'a mut self, id: &'a str) -> TaskOperation<'a> {
+        TaskOperation::new(id, self)
+    }
+
+    pub fn task_assignment<'a>(&'a mut self, id: &'a str) -> TaskAssignmentOperation<'a> {
+        TaskAssignmentOperation::new(id, self)
+    }
+
+    pub fn upload_session<'a>(&'a mut self, id: &'a str) -> UploadSessionOperation<'a> {
+        UploadSessionOperation::new(id, self)
+    }
+
+    pub fn users(&mut self) -> UsersOperation<'_> {
+        UsersOperation::new(self)
+    }
+
+    pub fn zip_download<'a>(&'a mut self, zip: &'a ZipDownload) -> ZipDownloadOperation<'a> {
+        ZipDownloadOperation::new(zip, self)
+    }
+
     pub async fn upload_file(&mut self, path: &Path, folder_id: &str) -> Result<File, SDKError> {
+        let file_size = fs::metadata(path).await?.len();
+        let filename = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| SDKError::InvalidFileName(path.to_owned()))?;
+        if file_size > self.chunked_upload_threshold {
+            return self
+                .upload_file_chunked(path, filename, folder_id, file_size)
+                .await;
+        }
+
+        let sha1 = sha1_file(path).await?;
         let file = fs::File::open(path).await?;
         let stream = FramedRead::new(file, BytesCodec::new());
+        self.upload_simple(filename, folder_id, &sha1, stream).await
+    }
 
-        let filename = path.file_name().unwrap().to_str();
+    /// Uploads everything read from `reader` as a new file named `name`. When
+    /// `size` is known the upload is checked with Box before any content is
+    /// read. Content larger than the chunked upload threshold, which includes
+    /// content of unknown size that turns out to be large, is first written to a
+    /// temporary file and sent through an upload session.
+    pub async fn upload_reader<R: AsyncRead + Unpin>(
+        &mut self,
+        mut reader: R,
+        name: &str,
+        folder_id: &str,
+        size: Option<u64>,
+    ) -> Result<File, SDKError> {
+        if let Some(size) = size {
+            self.preflight_upload(name, folder_id, size).await?;
+        }
+
+        // Read one byte past the threshold to find out which side of it we're on
+        let mut buffer = Vec::new();
+        (&mut reader)
+            .take(self.chunked_upload_threshold + 1)
+            .read_to_end(&mut buffer)
+            .await?;
+
+        if buffer.len() as u64 <= self.chunked_upload_threshold {
+            let sha1 = format!("{:x}", Sha1::digest(&buffer));
+            let stream = stream::once(future::ready(Ok::<_, io::Error>(Bytes::from(buffer))));
+            return self.upload_simple(name, folder_id, &sha1, stream).await;
+        }
+
+        // The name is made up here rather than taken from `name`, which could
+        // point anywhere once joined to a path
+        let path = std::env::temp_dir().join(format!(
+            "bx-upload-{}-{}",
+            std::process::id(),
+            TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let result = async {
+            let mut file = fs::File::create(&path).await?;
+            file.write_all(&buffer).await?;
+            io::copy(&mut reader, &mut file).await?;
+            file.flush().await?;
+            drop(file);
+
+            // Content of a known size was already checked before it was read
+            let metadata = fs::metadata(&path).await?;
+            if size.is_none() {
+                self.preflight_upload(name, folder_id, metadata.len())
+                    .await?;
+            }
+            let session = self
+                .create_upload_session(folder_id, name, metadata.len())
+                .await?;
+
+            let state = UploadState::new(&path, &session, &metadata);
+            match self.finish_chunked_upload(&path, state).await {
+                // The session can't be resumed without the temporary file, so
+                // it is aborted whatever went wrong
+                Err(err) if !is_rejection(&err) => {
+                    let _ = self.upload_session(session.id()).abort().await;
+                    Err(err)
+                }
+                result => result,
+            }
+        }
+        .await;
+
+        // The temporary file can't be resumed from once this process exits
+        if let Ok(state) = UploadState::load(&path).await {
+            let _ = state.remove().await;
+        }
+        let _ = fs::remove_file(&path).await;
+        result
+    }
+
+    async fn upload_simple<S>(
+        &mut self,
+        name: &str,
+        folder_id: &str,
+        sha1: &str,
+        stream: S,
+    ) -> Result<File, SDKError>
+    where
+        S: TryStream + Send + Sync + 'static,
+        S::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+        Bytes: From<S::Ok>,
+    {
         let attributes_json = json!({
-            "name": filename,
+            "name": name,
             "parent": {
                 "id": folder_id
             }
@@ -95,10 +345,233 @@ impl Client {
             .with_text_part("attributes", &attributes_json)
             .with_stream_part("file", stream);
 
-        let url = "https://upload.box.com/api/2.0/files/content";
+        let url = upload_url("/files/content");
+        let request = self
+            .network
+            .start_request(HTTPMethod::POST, &url)
+            .with_header("Content-MD5", sha1)
+            .with_body(Body::Multipart(form));
 
-        let response = self.multipart_upload(&url, form).await?;
+        let response = self.send(request).await?;
         let data: Collection<File> = response.deserialize().await?;
-        Ok(data.entries[0].to_owned())
+        data.entries
+            .into_iter()
+            .next()
+            .ok_or_else(|| SDKError::NothingUploaded(name.to_owned()))
+    }
+
+    /// Checks whether a file of `size` bytes named `name` could be uploaded to
+    /// the folder, without sending any content.
+    pub async fn preflight_upload(
+        &mut self,
+        name: &str,
+        folder_id: &str,
+        size: u64,
+    ) -> Result<UploadPreflight, SDKError> {
+        let body = json!({
+            "name": name,
+            "parent": {
+                "id": folder_id
+            },
+            "size": size,
+        });
+
+        match self.options("/files/content", body).await {
+            Ok(response) => Ok(response.deserialize().await?),
+            Err(SDKError::APIError { response }) => {
+                let error: ClientError = response.deserialize().await?;
+                Err(SDKError::UploadRejected(error))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn create_upload_session(
+        &mut self,
+        folder_id: &str,
+        file_name: &str,
+        file_size: u64,
+    ) -> Result<UploadSession, SDKError> {
+        let url = upload_url("/files/upload_sessions");
+        let body = json!({
+            "folder_id": folder_id,
+            "file_name": file_name,
+            "file_size": file_size,
+        });
+
+        let response = self.post(&url, body).await?;
+        let session: UploadSession = response.deserialize().await?;
+        Ok(session)
+    }
+
+    async fn upload_file_chunked(
+        &mut self,
+        path: &Path,
+        filename: &str,
+        folder_id: &str,
+        file_size: u64,
+    ) -> Result<File, SDKError> {
+        self.preflight_upload(filename, folder_id, file_size)
+            .await?;
+        let session = self
+            .create_upload_session(folder_id, filename, file_size)
+            .await?;
+
+        let state = UploadState::new(path, &session, &fs::metadata(path).await?);
+        state.save().await?;
+
+        self.finish_chunked_upload(path, state).await
+    }
+
+    /// Resumes a chunked upload of the file at `path` that was interrupted,
+    /// uploading only the parts Box hasn't received yet.
+    pub async fn resume_upload(&mut self, path: &Path) -> Result<File, SDKError> {
+        let mut state = UploadState::load(path).await?;
+        if !state.matches(&fs::metadata(path).await?) {
+            return Err(SDKError::FileChanged(path.to_owned()));
+        }
+
+        let session_id = state.session_id().to_owned();
+        let parts = self.upload_session(&session_id).list_parts().await?;
+        state.set_parts(parts);
+
+        self.finish_chunked_upload(path, state).await
+    }
+
+    pub(crate) fn chunked_upload_threshold(&self) -> u64 {
+        self.chunked_upload_threshold
+    }
+
+    pub(crate) fn download_part_size(&self) -> u64 {
+        self.download_part_size
+    }
+
+    pub(crate) fn download_concurrency(&self) -> usize {
+        self.download_concurrency
+    }
+
+    pub(crate) async fn finish_chunked_upload(
+        &mut self,
+        path: &Path,
+        mut state: UploadState,
+    ) -> Result<File, SDKError> {
+        let concurrency = self.upload_concurrency;
+        let session_id = state.session_id().to_owned();
+        let mut operation = self.upload_session(&session_id);
+
+        // The saved state is kept when parts fail to upload or the commit fails
+        // for a reason that may go away, so the upload can be resumed later.
+        let sha1 = operation
+            .upload_parts(path, &mut state, concurrency)
+            .await?;
+        let result = operation
+            .commit(state.parts(), &sha1, state.if_match())
+            .await;
+
+        match &result {
+            Err(error) if !is_rejection(error) => return result,
+            Err(_) => {
+                // Best effort: the original error is more useful than a failed abort
+                let _ = operation.abort().await;
+            }
+            Ok(_) => {}
+        }
+        // The file is already committed or the session is gone, so a state file
+        // that can't be removed isn't worth failing over; resuming from it would
+        // only report that the session no longer exists.
+        let _ = state.remove().await;
+        result
+    }
+
+    /// Comments on the file `file_id`. Users can be mentioned with `@[id:name]`.
+    pub async fn create_comment(
+        &mut self,
+        file_id: &str,
+        message: &str,
+    ) -> Result<Comment, SDKError> {
+        let url = format!("/comments?fields={}", COMMENT_FIELDS);
+        let body = json!({
+            message_field(message): message,
+            "item": { "type": "file", "id": file_id }
+        });
+
+        let response = self.post(&url, body).await?;
+        let comment: Comment = response.deserialize().await?;
+        Ok(comment)
+    }
+
+    pub async fn create_metadata_template(
+        &mut self,
+        template: NewMetadataTemplate,
+    ) -> Result<MetadataTemplate, SDKError> {
+        let response = self.post("/metadata_templates/schema", template).await?;
+        let template: MetadataTemplate = response.deserialize().await?;
+        Ok(template)
+    }
+
+    /// Runs a metadata query. Further pages of results are fetched as the
+    /// stream is read.
+    pub fn execute_metadata_query(
+        &mut self,
+        query: MetadataQuery,
+    ) -> impl Stream<Item = Result<Item, SDKError>> + '_ {
+        stream::try_unfold(Some((self, query)), |state| async move {
+            let (client, query) = match state {
+                Some(state) => state,
+                None => return Ok(None),
+            };
+            let response = client
+                .post("/metadata_queries/execute_read", &query)
+                .await?;
+            let page: MarkerCollection<Item> = response.deserialize().await?;
+
+            let next = page
+                .next_marker
+                .filter(|marker| !marker.is_empty())
+                .map(|marker| (client, query.marker(marker)));
+            let entries = stream::iter(page.entries.into_iter().map(Ok));
+            Ok::<_, SDKError>(Some((entries, next)))
+        })
+        .try_flatten()
+    }
+
+    pub async fn create_task(&mut self, task: NewTask) -> Result<Task, SDKError> {
+        let response = self.post("/tasks", task).await?;
+        let task: Task = response.deserialize().await?;
+        Ok(task)
+    }
+
+    /// Bundles files and folders into a single archive to download.
+    pub async fn create_zip_download(
+        &mut self,
+        zip: NewZipDownload,
+    ) -> Result<ZipDownload, SDKError> {
+        let response = self.post("/zip_downloads", zip).await?;
+        let zip: ZipDownload = response.deserialize().await?;
+        Ok(zip)
+    }
+
+    pub async fn create_web_link(&mut self, web_link: NewWebLink) -> Result<WebLink, SDKError> {
+        let response = self.post("/web_links", web_link).await?;
+        let web_link: WebLink = response.deserialize().await?;
+        Ok(web_link)
+    }
+}
+
+/// Whether Box refused the request outright, as opposed to failing in a way
+/// that retrying could fix. An expired token, a timeout or rate limiting
+/// aren't rejections, so the upload can still be resumed after them.
+fn is_rejection(error: &SDKError) -> bool {
+    match error {
+        SDKError::APIError { response } => matches!(
+            response.status(),
+            StatusCode::BAD_REQUEST
+                | StatusCode::FORBIDDEN
+                | StatusCode::NOT_FOUND
+                | StatusCode::CONFLICT
+                | StatusCode::PRECONDITION_FAILED
+        ),
+        SDKError::NameConflict(_) | SDKError::UploadRejected(_) => true,
+        _ => false,
     }
 }
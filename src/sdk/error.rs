@@ -1,4 +1,6 @@
+use super::models::ClientError;
 use super::Response;
+use std::path::PathBuf;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,6 +17,44 @@ pub enum SDKError {
     InvalidHeader(#[from] http::Error),
     #[error("error reading file: {0}")]
     FileIO(#[from] tokio::io::Error),
+    #[error("no upload to resume for {0}")]
+    NoUploadToResume(PathBuf),
+    #[error("{0} does not have a valid file name")]
+    InvalidFileName(PathBuf),
+    #[error("{0} has changed since the upload was started")]
+    FileChanged(PathBuf),
+    #[error("upload session {0} was still processing after {1} commit attempts")]
+    CommitTimedOut(String, u32),
+    #[error("upload session {0} was committed but no file was returned")]
+    NothingCommitted(String),
+    #[error("{0} was uploaded but no file was returned")]
+    NothingUploaded(String),
+    #[error("integrity check failed for {path}: expected SHA1 {expected}, got {actual}")]
+    Integrity {
+        path: PathBuf,
+        expected: String,
+        actual: String,
+    },
+    #[error("integrity check failed for streamed content: expected SHA1 {expected}, got {actual}")]
+    StreamIntegrity { expected: String, actual: String },
+    #[error("file {0} has no SHA1 to check against")]
+    MissingSha1(String),
+    #[error("the requested range was ignored by the server")]
+    RangeIgnored,
+    #[error("no item at {0}")]
+    PathNotFound(String),
+    #[error("representation {0} is not available")]
+    RepresentationUnavailable(String),
+    #[error("representation {0} was not ready after {1:?}")]
+    RepresentationTimedOut(String, std::time::Duration),
+    #[error("zip download failed after {downloaded} of {total} files")]
+    ZipDownloadFailed { downloaded: u64, total: u64 },
+    #[error("zip download was still in progress after {0:?}")]
+    ZipDownloadTimedOut(std::time::Duration),
+    #[error("name already in use: {}", .0.message().unwrap_or("unknown item"))]
+    NameConflict(ClientError),
+    #[error("upload rejected: {}", .0.message().unwrap_or("unknown reason"))]
+    UploadRejected(ClientError),
     #[error("box api error: {}", .response.status())]
     APIError { response: Box<Response> },
 }
@@ -1,11 +1,15 @@
-mod auth;
+pub mod auth;
+mod checksum;
 mod client;
 mod error;
-mod models;
+pub mod models;
 mod network;
 pub mod operations;
+mod upload_state;
 
+pub use checksum::*;
 pub use client::Client;
 pub use error::*;
 pub use models::*;
 pub use network::*;
+pub use upload_state::*;
@@ -0,0 +1,68 @@
+use super::item::Item;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The body Box returns alongside an unsuccessful response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClientError {
+    status: Option<u16>,
+    code: Option<String>,
+    message: Option<String>,
+    context_info: Option<Value>,
+    help_url: Option<String>,
+    request_id: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl ClientError {
+    pub fn status(&self) -> Option<u16> {
+        self.status
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn context_info(&self) -> Option<&Value> {
+        self.context_info.as_ref()
+    }
+
+    pub fn help_url(&self) -> Option<&str> {
+        self.help_url.as_deref()
+    }
+
+    pub fn request_id(&self) -> Option<&str> {
+        self.request_id.as_deref()
+    }
+
+    /// The items already using the requested name, when Box rejects a request
+    /// with a 409 `item_name_in_use` error.
+    pub fn conflicts(&self) -> Vec<Item> {
+        let conflicts = match self
+            .context_info
+            .as_ref()
+            .and_then(|info| info.get("conflicts"))
+        {
+            Some(conflicts) => conflicts.clone(),
+            None => return Vec::new(),
+        };
+
+        // Some endpoints return a single item rather than a list
+        match conflicts {
+            Value::Array(items) => items
+                .into_iter()
+                .filter_map(|item| serde_json::from_value(item).ok())
+                .collect(),
+            item => serde_json::from_value(item).ok().into_iter().collect(),
+        }
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
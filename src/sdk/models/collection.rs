@@ -1,7 +1,19 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Collection<T> {
     pub total_count: u64,
     pub entries: Vec<T>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A page of a list that's paged with markers rather than offsets.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MarkerCollection<T> {
+    pub entries: Vec<T>,
+    pub next_marker: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
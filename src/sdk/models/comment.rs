@@ -0,0 +1,135 @@
+use super::user::User;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// What a comment was left on: a file, or another comment for replies.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CommentItem {
+    id: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl CommentItem {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn item_type(&self) -> &str {
+        &self.item_type
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Comment {
+    id: String,
+    is_reply_comment: Option<bool>,
+    message: Option<String>,
+    tagged_message: Option<String>,
+    created_by: Option<Box<User>>,
+    created_at: Option<DateTime<FixedOffset>>,
+    modified_at: Option<DateTime<FixedOffset>>,
+    item: Option<CommentItem>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Comment {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn is_reply_comment(&self) -> Option<bool> {
+        self.is_reply_comment
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn tagged_message(&self) -> Option<&str> {
+        self.tagged_message.as_deref()
+    }
+
+    pub fn created_by(&self) -> Option<&User> {
+        self.created_by.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.modified_at
+    }
+
+    pub fn item(&self) -> Option<&CommentItem> {
+        self.item.as_ref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+
+    /// Groups a file's comments into threads, oldest first. A reply goes under
+    /// the comment it names as its item, or under the comment before it when
+    /// Box only names the file.
+    pub fn threads(mut comments: Vec<Comment>) -> Vec<CommentThread> {
+        comments.sort_by_key(|comment| comment.created_at);
+
+        let mut threads: Vec<CommentThread> = Vec::new();
+        for comment in comments {
+            if !comment.is_reply_comment.unwrap_or(false) {
+                threads.push(CommentThread {
+                    comment,
+                    replies: Vec::new(),
+                });
+                continue;
+            }
+
+            let parent_id = comment
+                .item
+                .as_ref()
+                .filter(|item| item.item_type == "comment")
+                .map(|item| item.id.clone());
+            let thread = match parent_id {
+                Some(parent_id) => threads
+                    .iter_mut()
+                    .find(|thread| thread.comment.id == parent_id),
+                None => threads.last_mut(),
+            };
+            match thread {
+                Some(thread) => thread.replies.push(comment),
+                None => threads.push(CommentThread {
+                    comment,
+                    replies: Vec::new(),
+                }),
+            }
+        }
+        threads
+    }
+}
+
+/// A top-level comment and the replies to it, in the order they were made.
+#[derive(Serialize, Debug, Clone)]
+pub struct CommentThread {
+    comment: Comment,
+    replies: Vec<Comment>,
+}
+
+impl CommentThread {
+    pub fn comment(&self) -> &Comment {
+        &self.comment
+    }
+
+    pub fn replies(&self) -> &[Comment] {
+        &self.replies
+    }
+}
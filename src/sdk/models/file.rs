@@ -2,14 +2,19 @@ use super::collection::Collection;
 use super::folder::Folder;
 use super::shared_link::SharedLink;
 use super::user::User;
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum ItemStatus {
     Active,
     Trashed,
     Deleted,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -19,30 +24,296 @@ pub struct File {
     name: Option<String>,
     sequence_id: Option<String>,
     allowed_invitee_roles: Option<Vec<String>>,
-    content_created_at: Option<String>,
-    content_modified_at: Option<String>,
-    created_at: Option<String>,
+    collections: Option<Vec<FileCollection>>,
+    content_created_at: Option<DateTime<FixedOffset>>,
+    content_modified_at: Option<DateTime<FixedOffset>>,
+    created_at: Option<DateTime<FixedOffset>>,
     created_by: Option<Box<User>>,
     description: Option<String>,
     file_version: Option<Box<FileVersion>>,
     has_collaborations: Option<bool>,
     is_externally_owned: Option<bool>,
     item_status: Option<Box<ItemStatus>>,
-    modified_at: Option<String>,
+    lock: Option<Box<FileLock>>,
+    modified_at: Option<DateTime<FixedOffset>>,
     modified_by: Option<Box<User>>,
     owned_by: Option<Box<User>>,
     parent: Option<Box<Folder>>,
     path_collection: Option<Collection<Folder>>,
-    purged_at: Option<String>,
+    purged_at: Option<DateTime<FixedOffset>>,
     sha1: Option<String>,
     shared_link: Option<SharedLink>,
     size: Option<u64>,
     tags: Option<Vec<String>>,
-    trashed_at: Option<String>,
+    trashed_at: Option<DateTime<FixedOffset>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl File {
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn sequence_id(&self) -> Option<&str> {
+        self.sequence_id.as_deref()
+    }
+
+    pub fn allowed_invitee_roles(&self) -> Option<&[String]> {
+        self.allowed_invitee_roles.as_deref()
+    }
+
+    /// The collections the file is in, like the user's favorites.
+    pub fn collections(&self) -> Option<&[FileCollection]> {
+        self.collections.as_deref()
+    }
+
+    pub fn content_created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.content_created_at
+    }
+
+    pub fn content_modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.content_modified_at
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn created_by(&self) -> Option<&User> {
+        self.created_by.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn file_version(&self) -> Option<&FileVersion> {
+        self.file_version.as_deref()
+    }
+
+    pub fn has_collaborations(&self) -> Option<bool> {
+        self.has_collaborations
+    }
+
+    pub fn is_externally_owned(&self) -> Option<bool> {
+        self.is_externally_owned
+    }
+
+    pub fn item_status(&self) -> Option<&ItemStatus> {
+        self.item_status.as_deref()
+    }
+
+    pub fn lock(&self) -> Option<&FileLock> {
+        self.lock.as_deref()
+    }
+
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.modified_at
+    }
+
+    pub fn modified_by(&self) -> Option<&User> {
+        self.modified_by.as_deref()
+    }
+
+    pub fn owned_by(&self) -> Option<&User> {
+        self.owned_by.as_deref()
+    }
+
+    pub fn parent(&self) -> Option<&Folder> {
+        self.parent.as_deref()
+    }
+
+    pub fn path_collection(&self) -> Option<&Collection<Folder>> {
+        self.path_collection.as_ref()
+    }
+
+    pub fn purged_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.purged_at
+    }
+
+    pub fn sha1(&self) -> Option<&str> {
+        self.sha1.as_deref()
+    }
+
+    pub fn shared_link(&self) -> Option<&SharedLink> {
+        self.shared_link.as_ref()
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    pub fn tags(&self) -> Option<&[String]> {
+        self.tags.as_deref()
+    }
+
+    pub fn trashed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.trashed_at
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct FileVersion {
     id: String,
     sha1: String,
+    name: Option<String>,
+    size: Option<u64>,
+    version_number: Option<String>,
+    created_at: Option<DateTime<FixedOffset>>,
+    modified_at: Option<DateTime<FixedOffset>>,
+    modified_by: Option<Box<User>>,
+    trashed_at: Option<DateTime<FixedOffset>>,
+    trashed_by: Option<Box<User>>,
+    restored_at: Option<DateTime<FixedOffset>>,
+    restored_by: Option<Box<User>>,
+    purged_at: Option<DateTime<FixedOffset>>,
+    uploader_display_name: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl FileVersion {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn sha1(&self) -> &str {
+        &self.sha1
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn size(&self) -> Option<u64> {
+        self.size
+    }
+
+    pub fn version_number(&self) -> Option<&str> {
+        self.version_number.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.modified_at
+    }
+
+    pub fn modified_by(&self) -> Option<&User> {
+        self.modified_by.as_deref()
+    }
+
+    pub fn trashed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.trashed_at
+    }
+
+    pub fn trashed_by(&self) -> Option<&User> {
+        self.trashed_by.as_deref()
+    }
+
+    pub fn restored_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.restored_at
+    }
+
+    pub fn restored_by(&self) -> Option<&User> {
+        self.restored_by.as_deref()
+    }
+
+    pub fn purged_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.purged_at
+    }
+
+    pub fn uploader_display_name(&self) -> Option<&str> {
+        self.uploader_display_name.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// A collection a file has been added to.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileCollection {
+    id: String,
+    name: Option<String>,
+    collection_type: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl FileCollection {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn collection_type(&self) -> Option<&str> {
+        self.collection_type.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// A lock on a file, which stops anyone but its creator from changing the file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FileLock {
+    id: String,
+    created_at: Option<DateTime<FixedOffset>>,
+    created_by: Option<Box<User>>,
+    expired_at: Option<DateTime<FixedOffset>>,
+    is_download_prevented: Option<bool>,
+    app_type: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl FileLock {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn created_by(&self) -> Option<&User> {
+        self.created_by.as_deref()
+    }
+
+    pub fn expired_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.expired_at
+    }
+
+    pub fn is_download_prevented(&self) -> Option<bool> {
+        self.is_download_prevented
+    }
+
+    pub fn app_type(&self) -> Option<&str> {
+        self.app_type.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
 }
@@ -1,12 +1,39 @@
 use super::file::File;
 use super::folder::Folder;
+use super::web_link::WebLink;
 
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "lowercase")]
 #[serde(tag = "type")]
+#[non_exhaustive]
 pub enum Item {
     File(Box<File>),
     Folder(Box<Folder>),
+    #[serde(rename = "web_link")]
+    WebLink(Box<WebLink>),
+    #[serde(untagged)]
+    Unknown(Map<String, Value>),
+}
+
+impl Item {
+    pub fn id(&self) -> &str {
+        match self {
+            Item::File(file) => file.id(),
+            Item::Folder(folder) => folder.id(),
+            Item::WebLink(web_link) => web_link.id(),
+            Item::Unknown(item) => item.get("id").and_then(Value::as_str).unwrap_or(""),
+        }
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        match self {
+            Item::File(file) => file.name(),
+            Item::Folder(folder) => folder.name(),
+            Item::WebLink(web_link) => web_link.name(),
+            Item::Unknown(item) => item.get("name").and_then(Value::as_str),
+        }
+    }
 }
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The values of one metadata template applied to a file or folder. The
+/// template's fields are kept alongside the `$`-prefixed system fields.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Metadata {
+    #[serde(rename = "$id")]
+    id: Option<String>,
+    #[serde(rename = "$type")]
+    metadata_type: Option<String>,
+    #[serde(rename = "$parent")]
+    parent: Option<String>,
+    #[serde(rename = "$scope")]
+    scope: String,
+    #[serde(rename = "$template")]
+    template: String,
+    #[serde(rename = "$version")]
+    version: Option<u64>,
+    #[serde(rename = "$typeVersion")]
+    type_version: Option<u64>,
+    #[serde(rename = "$canEdit")]
+    can_edit: Option<bool>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Metadata {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn metadata_type(&self) -> Option<&str> {
+        self.metadata_type.as_deref()
+    }
+
+    pub fn parent(&self) -> Option<&str> {
+        self.parent.as_deref()
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    pub fn version(&self) -> Option<u64> {
+        self.version
+    }
+
+    pub fn type_version(&self) -> Option<u64> {
+        self.type_version
+    }
+
+    pub fn can_edit(&self) -> Option<bool> {
+        self.can_edit
+    }
+
+    /// The values of the template's fields, leaving out system fields.
+    pub fn fields(&self) -> impl Iterator<Item = (&String, &Value)> {
+        self.extra.iter().filter(|(key, _)| !key.starts_with('$'))
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum MetadataFieldType {
+    String,
+    Float,
+    Date,
+    Enum,
+    MultiSelect,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// The schema that metadata instances on files and folders follow.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataTemplate {
+    id: Option<String>,
+    scope: String,
+    template_key: String,
+    display_name: Option<String>,
+    hidden: Option<bool>,
+    fields: Option<Vec<MetadataField>>,
+    copy_instance_on_item_copy: Option<bool>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl MetadataTemplate {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn scope(&self) -> &str {
+        &self.scope
+    }
+
+    pub fn template_key(&self) -> &str {
+        &self.template_key
+    }
+
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    pub fn hidden(&self) -> Option<bool> {
+        self.hidden
+    }
+
+    pub fn fields(&self) -> Option<&[MetadataField]> {
+        self.fields.as_deref()
+    }
+
+    pub fn copy_instance_on_item_copy(&self) -> Option<bool> {
+        self.copy_instance_on_item_copy
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataField {
+    id: Option<String>,
+    #[serde(rename = "type")]
+    field_type: MetadataFieldType,
+    key: String,
+    display_name: Option<String>,
+    description: Option<String>,
+    hidden: Option<bool>,
+    options: Option<Vec<MetadataFieldOption>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl MetadataField {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn field_type(&self) -> &MetadataFieldType {
+        &self.field_type
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn display_name(&self) -> Option<&str> {
+        self.display_name.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn hidden(&self) -> Option<bool> {
+        self.hidden
+    }
+
+    pub fn options(&self) -> Option<&[MetadataFieldOption]> {
+        self.options.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// One of the values an enum or multi-select field can take.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MetadataFieldOption {
+    id: Option<String>,
+    key: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl MetadataFieldOption {
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
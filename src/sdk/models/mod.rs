@@ -1,13 +1,31 @@
+mod client_error;
 mod collection;
+mod comment;
 mod file;
 mod folder;
 mod item;
+mod metadata;
+mod metadata_template;
+mod representation;
 mod shared_link;
+mod task;
+mod upload_session;
 mod user;
+mod web_link;
+mod zip_download;
 
+pub use client_error::*;
 pub use collection::*;
+pub use comment::*;
 pub use file::*;
 pub use folder::*;
 pub use item::*;
+pub use metadata::*;
+pub use metadata_template::*;
+pub use representation::*;
 pub use shared_link::*;
+pub use task::*;
+pub use upload_session::*;
 pub use user::*;
+pub use web_link::*;
+pub use zip_download::*;
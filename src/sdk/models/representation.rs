@@ -0,0 +1,112 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum RepresentationState {
+    Success,
+    Viewable,
+    Pending,
+    /// Generation hasn't been requested yet.
+    #[serde(rename = "none")]
+    NotStarted,
+    Error,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepresentationStatus {
+    state: RepresentationState,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl RepresentationStatus {
+    pub fn state(&self) -> &RepresentationState {
+        &self.state
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepresentationInfo {
+    url: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl RepresentationInfo {
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RepresentationContent {
+    url_template: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl RepresentationContent {
+    pub fn url_template(&self) -> &str {
+        &self.url_template
+    }
+
+    /// The URL of one asset of the representation. Representations made of a
+    /// single file use an empty `asset_path`; paged ones use names like `1.png`.
+    pub fn url(&self, asset_path: &str) -> String {
+        self.url_template.replace("{+asset_path}", asset_path)
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// A generated version of a file's content, like a thumbnail or a PDF.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Representation {
+    representation: String,
+    properties: Option<Map<String, Value>>,
+    info: Option<RepresentationInfo>,
+    status: Option<RepresentationStatus>,
+    content: Option<RepresentationContent>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Representation {
+    pub fn representation(&self) -> &str {
+        &self.representation
+    }
+
+    pub fn properties(&self) -> Option<&Map<String, Value>> {
+        self.properties.as_ref()
+    }
+
+    pub fn info(&self) -> Option<&RepresentationInfo> {
+        self.info.as_ref()
+    }
+
+    pub fn status(&self) -> Option<&RepresentationStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn content(&self) -> Option<&RepresentationContent> {
+        self.content.as_ref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
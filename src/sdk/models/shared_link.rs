@@ -1,24 +1,48 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
+#[non_exhaustive]
 pub enum SharedLinkAccess {
     Open,
     Company,
     Collaborators,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
-#[derive(Deserialize, Serialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum SharedLinkPermission {
     CanDownload,
     CanPreview,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct SharedLinkPermissions {
     can_download: bool,
     can_preview: bool,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl SharedLinkPermissions {
+    pub fn can_download(&self) -> bool {
+        self.can_download
+    }
+
+    pub fn can_preview(&self) -> bool {
+        self.can_preview
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -30,7 +54,55 @@ pub struct SharedLink {
     is_password_enabled: bool,
     permissions: SharedLinkPermissions,
     preview_count: u64,
-    unshared_at: Option<String>,
+    unshared_at: Option<DateTime<FixedOffset>>,
     url: String,
     vanity_url: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl SharedLink {
+    pub fn access(&self) -> &SharedLinkAccess {
+        &self.access
+    }
+
+    pub fn download_count(&self) -> u64 {
+        self.download_count
+    }
+
+    pub fn effective_access(&self) -> &SharedLinkAccess {
+        &self.effective_access
+    }
+
+    pub fn effective_permission(&self) -> &SharedLinkPermission {
+        &self.effective_permission
+    }
+
+    pub fn is_password_enabled(&self) -> bool {
+        self.is_password_enabled
+    }
+
+    pub fn permissions(&self) -> &SharedLinkPermissions {
+        &self.permissions
+    }
+
+    pub fn preview_count(&self) -> u64 {
+        self.preview_count
+    }
+
+    pub fn unshared_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.unshared_at
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn vanity_url(&self) -> Option<&str> {
+        self.vanity_url.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
 }
@@ -0,0 +1,149 @@
+use super::collection::Collection;
+use super::file::File;
+use super::user::User;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TaskAction {
+    Review,
+    Complete,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum ResolutionState {
+    Completed,
+    Incomplete,
+    Approved,
+    Rejected,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A to-do on a file, assigned to one or more users.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Task {
+    id: String,
+    item: Option<Box<File>>,
+    action: Option<TaskAction>,
+    message: Option<String>,
+    due_at: Option<DateTime<FixedOffset>>,
+    is_completed: Option<bool>,
+    completion_rule: Option<String>,
+    task_assignment_collection: Option<Collection<TaskAssignment>>,
+    created_by: Option<Box<User>>,
+    created_at: Option<DateTime<FixedOffset>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Task {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn item(&self) -> Option<&File> {
+        self.item.as_deref()
+    }
+
+    pub fn action(&self) -> Option<&TaskAction> {
+        self.action.as_ref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn due_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.due_at
+    }
+
+    pub fn is_completed(&self) -> Option<bool> {
+        self.is_completed
+    }
+
+    pub fn completion_rule(&self) -> Option<&str> {
+        self.completion_rule.as_deref()
+    }
+
+    pub fn task_assignment_collection(&self) -> Option<&Collection<TaskAssignment>> {
+        self.task_assignment_collection.as_ref()
+    }
+
+    pub fn created_by(&self) -> Option<&User> {
+        self.created_by.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// One user's part in a task.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TaskAssignment {
+    id: String,
+    item: Option<Box<File>>,
+    assigned_to: Option<Box<User>>,
+    assigned_by: Option<Box<User>>,
+    message: Option<String>,
+    resolution_state: Option<ResolutionState>,
+    assigned_at: Option<DateTime<FixedOffset>>,
+    reminded_at: Option<DateTime<FixedOffset>>,
+    completed_at: Option<DateTime<FixedOffset>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl TaskAssignment {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn item(&self) -> Option<&File> {
+        self.item.as_deref()
+    }
+
+    pub fn assigned_to(&self) -> Option<&User> {
+        self.assigned_to.as_deref()
+    }
+
+    pub fn assigned_by(&self) -> Option<&User> {
+        self.assigned_by.as_deref()
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn resolution_state(&self) -> Option<&ResolutionState> {
+        self.resolution_state.as_ref()
+    }
+
+    pub fn assigned_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.assigned_at
+    }
+
+    pub fn reminded_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.reminded_at
+    }
+
+    pub fn completed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.completed_at
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
@@ -0,0 +1,88 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadSession {
+    id: String,
+    session_expires_at: Option<DateTime<FixedOffset>>,
+    part_size: u64,
+    total_parts: u64,
+    num_parts_processed: Option<u64>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl UploadSession {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn session_expires_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.session_expires_at
+    }
+
+    pub fn part_size(&self) -> u64 {
+        self.part_size
+    }
+
+    pub fn total_parts(&self) -> u64 {
+        self.total_parts
+    }
+
+    pub fn num_parts_processed(&self) -> Option<u64> {
+        self.num_parts_processed
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadPart {
+    part_id: String,
+    offset: u64,
+    size: u64,
+    sha1: String,
+}
+
+impl UploadPart {
+    pub fn part_id(&self) -> &str {
+        &self.part_id
+    }
+
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn sha1(&self) -> &str {
+        &self.sha1
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadPreflight {
+    upload_url: Option<String>,
+    upload_token: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl UploadPreflight {
+    pub fn upload_url(&self) -> Option<&str> {
+        self.upload_url.as_deref()
+    }
+
+    pub fn upload_token(&self) -> Option<&str> {
+        self.upload_token.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
@@ -1,8 +1,199 @@
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum UserRole {
+    Admin,
+    Coadmin,
+    User,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum UserStatus {
+    Active,
+    Inactive,
+    CannotDeleteEdit,
+    CannotDeleteEditUpload,
+    #[serde(untagged)]
+    Unknown(String),
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
-pub struct User {
+pub struct Enterprise {
     id: String,
+    name: Option<String>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl Enterprise {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TrackingCode {
     name: String,
-    login: String,
+    value: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl TrackingCode {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NotificationEmail {
+    email: String,
+    is_confirmed: bool,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl NotificationEmail {
+    pub fn email(&self) -> &str {
+        &self.email
+    }
+
+    pub fn is_confirmed(&self) -> bool {
+        self.is_confirmed
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct User {
+    id: String,
+    name: Option<String>,
+    login: Option<String>,
+    created_at: Option<DateTime<FixedOffset>>,
+    modified_at: Option<DateTime<FixedOffset>>,
+    language: Option<String>,
+    timezone: Option<String>,
+    space_amount: Option<i64>,
+    space_used: Option<u64>,
+    max_upload_size: Option<u64>,
+    status: Option<UserStatus>,
+    role: Option<UserRole>,
+    job_title: Option<String>,
+    phone: Option<String>,
+    address: Option<String>,
+    avatar_url: Option<String>,
+    enterprise: Option<Enterprise>,
+    tracking_codes: Option<Vec<TrackingCode>>,
+    notification_email: Option<NotificationEmail>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl User {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn login(&self) -> Option<&str> {
+        self.login.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.modified_at
+    }
+
+    pub fn language(&self) -> Option<&str> {
+        self.language.as_deref()
+    }
+
+    pub fn timezone(&self) -> Option<&str> {
+        self.timezone.as_deref()
+    }
+
+    pub fn space_amount(&self) -> Option<i64> {
+        self.space_amount
+    }
+
+    pub fn space_used(&self) -> Option<u64> {
+        self.space_used
+    }
+
+    pub fn max_upload_size(&self) -> Option<u64> {
+        self.max_upload_size
+    }
+
+    pub fn status(&self) -> Option<&UserStatus> {
+        self.status.as_ref()
+    }
+
+    pub fn role(&self) -> Option<&UserRole> {
+        self.role.as_ref()
+    }
+
+    pub fn job_title(&self) -> Option<&str> {
+        self.job_title.as_deref()
+    }
+
+    pub fn phone(&self) -> Option<&str> {
+        self.phone.as_deref()
+    }
+
+    pub fn address(&self) -> Option<&str> {
+        self.address.as_deref()
+    }
+
+    pub fn avatar_url(&self) -> Option<&str> {
+        self.avatar_url.as_deref()
+    }
+
+    pub fn enterprise(&self) -> Option<&Enterprise> {
+        self.enterprise.as_ref()
+    }
+
+    pub fn tracking_codes(&self) -> Option<&[TrackingCode]> {
+        self.tracking_codes.as_deref()
+    }
+
+    pub fn notification_email(&self) -> Option<&NotificationEmail> {
+        self.notification_email.as_ref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
 }
@@ -0,0 +1,105 @@
+use super::collection::Collection;
+use super::file::ItemStatus;
+use super::folder::Folder;
+use super::shared_link::SharedLink;
+use super::user::User;
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct WebLink {
+    etag: Option<String>,
+    id: String,
+    name: Option<String>,
+    sequence_id: Option<String>,
+    url: Option<String>,
+    description: Option<String>,
+    created_at: Option<DateTime<FixedOffset>>,
+    created_by: Option<Box<User>>,
+    item_status: Option<Box<ItemStatus>>,
+    modified_at: Option<DateTime<FixedOffset>>,
+    modified_by: Option<Box<User>>,
+    owned_by: Option<Box<User>>,
+    parent: Option<Box<Folder>>,
+    path_collection: Option<Collection<Folder>>,
+    purged_at: Option<DateTime<FixedOffset>>,
+    shared_link: Option<SharedLink>,
+    trashed_at: Option<DateTime<FixedOffset>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl WebLink {
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    pub fn sequence_id(&self) -> Option<&str> {
+        self.sequence_id.as_deref()
+    }
+
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn created_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.created_at
+    }
+
+    pub fn created_by(&self) -> Option<&User> {
+        self.created_by.as_deref()
+    }
+
+    pub fn item_status(&self) -> Option<&ItemStatus> {
+        self.item_status.as_deref()
+    }
+
+    pub fn modified_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.modified_at
+    }
+
+    pub fn modified_by(&self) -> Option<&User> {
+        self.modified_by.as_deref()
+    }
+
+    pub fn owned_by(&self) -> Option<&User> {
+        self.owned_by.as_deref()
+    }
+
+    pub fn parent(&self) -> Option<&Folder> {
+        self.parent.as_deref()
+    }
+
+    pub fn path_collection(&self) -> Option<&Collection<Folder>> {
+        self.path_collection.as_ref()
+    }
+
+    pub fn purged_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.purged_at
+    }
+
+    pub fn shared_link(&self) -> Option<&SharedLink> {
+        self.shared_link.as_ref()
+    }
+
+    pub fn trashed_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.trashed_at
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
@@ -0,0 +1,121 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ZipDownloadState {
+    InProgress,
+    Failed,
+    Succeeded,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// An archive of several files and folders, ready to be downloaded from
+/// `download_url` until it expires.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ZipDownload {
+    download_url: String,
+    status_url: String,
+    expires_at: Option<DateTime<FixedOffset>>,
+    name_conflicts: Option<Vec<Vec<ZipDownloadConflict>>>,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl ZipDownload {
+    pub fn download_url(&self) -> &str {
+        &self.download_url
+    }
+
+    pub fn status_url(&self) -> &str {
+        &self.status_url
+    }
+
+    pub fn expires_at(&self) -> Option<DateTime<FixedOffset>> {
+        self.expires_at
+    }
+
+    pub fn name_conflicts(&self) -> Option<&[Vec<ZipDownloadConflict>]> {
+        self.name_conflicts.as_deref()
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// An item that was given a new name in the archive because another item
+/// had the same name.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ZipDownloadConflict {
+    id: String,
+    #[serde(rename = "type")]
+    item_type: String,
+    original_name: String,
+    download_name: String,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl ZipDownloadConflict {
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    pub fn item_type(&self) -> &str {
+        &self.item_type
+    }
+
+    pub fn original_name(&self) -> &str {
+        &self.original_name
+    }
+
+    pub fn download_name(&self) -> &str {
+        &self.download_name
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
+
+/// How far a zip download got, including the items that had to be left out.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ZipDownloadStatus {
+    total_file_count: u64,
+    downloaded_file_count: u64,
+    skipped_file_count: u64,
+    skipped_folder_count: u64,
+    state: ZipDownloadState,
+    #[serde(flatten)]
+    extra: Map<String, Value>,
+}
+
+impl ZipDownloadStatus {
+    pub fn total_file_count(&self) -> u64 {
+        self.total_file_count
+    }
+
+    pub fn downloaded_file_count(&self) -> u64 {
+        self.downloaded_file_count
+    }
+
+    pub fn skipped_file_count(&self) -> u64 {
+        self.skipped_file_count
+    }
+
+    pub fn skipped_folder_count(&self) -> u64 {
+        self.skipped_folder_count
+    }
+
+    pub fn state(&self) -> &ZipDownloadState {
+        &self.state
+    }
+
+    pub fn extra(&self) -> &Map<String, Value> {
+        &self.extra
+    }
+}
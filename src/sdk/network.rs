@@ -34,6 +34,7 @@ impl From<HTTPMethod> for ReqwestMethod {
 pub enum Body {
     Empty,
     JSON(Value),
+    Bytes(Bytes),
     Multipart(MultipartBody),
 }
 
@@ -83,6 +84,7 @@ impl Request {
         self.req = match body {
             Body::Empty => self.req,
             Body::JSON(json) => self.req.body(json.to_string()),
+            Body::Bytes(bytes) => self.req.body(bytes),
             Body::Multipart(body) => self.req.multipart(body.form),
         };
         self
@@ -130,6 +132,13 @@ impl From<ReqwestResponse> for Response {
     }
 }
 
+pub(crate) fn upload_url(path: &str) -> String {
+    let upload_root =
+        env::var("BOX_UPLOAD_ROOT").unwrap_or_else(|_| "https://upload.box.com/api/2.0".to_owned());
+    format!("{}{}", upload_root, path)
+}
+
+#[derive(Default)]
 pub struct NetworkAgent {
     http_client: ReqwestClient,
 }
@@ -143,7 +152,8 @@ impl NetworkAgent {
 
     pub fn start_request(&self, method: HTTPMethod, url: &str) -> Request {
         let req = if url.starts_with('/') {
-            let api_root = env::var("BOX_API_ROOT").unwrap_or_else(|_| "https://api.box.com/2.0".to_owned());
+            let api_root =
+                env::var("BOX_API_ROOT").unwrap_or_else(|_| "https://api.box.com/2.0".to_owned());
             let absolute_url = format!("{}{}", api_root, url);
             self.http_client.request(method.into(), &absolute_url)
         } else {
@@ -163,7 +173,9 @@ impl NetworkAgent {
         if response.status().is_success() {
             Ok(response)
         } else {
-            Err(SDKError::APIError { response: Box::new(response) })
+            Err(SDKError::APIError {
+                response: Box::new(response),
+            })
         }
     }
 }
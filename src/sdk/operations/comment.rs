@@ -0,0 +1,64 @@
+use crate::sdk::models::Comment;
+use crate::sdk::{Client, SDKError};
+use serde_json::json;
+
+pub(crate) const COMMENT_FIELDS: &str =
+    "is_reply_comment,message,tagged_message,created_by,created_at,modified_at,item";
+
+pub struct CommentOperation<'a> {
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> CommentOperation<'a> {
+    pub fn new(id: &'a str, client: &'a mut Client) -> CommentOperation<'a> {
+        CommentOperation { id, client }
+    }
+
+    pub async fn get(&mut self) -> Result<Comment, SDKError> {
+        let url = format!("/comments/{}?fields={}", self.id, COMMENT_FIELDS);
+        let response = self.client.get(&url).await?;
+
+        let comment: Comment = response.deserialize().await?;
+
+        Ok(comment)
+    }
+
+    /// Replies to the comment. Users can be mentioned with `@[id:name]`.
+    pub async fn reply(&mut self, message: &str) -> Result<Comment, SDKError> {
+        let url = format!("/comments?fields={}", COMMENT_FIELDS);
+        let body = json!({
+            message_field(message): message,
+            "item": { "type": "comment", "id": self.id }
+        });
+
+        let response = self.client.post(&url, body).await?;
+        let comment: Comment = response.deserialize().await?;
+        Ok(comment)
+    }
+
+    pub async fn update(&mut self, message: &str) -> Result<Comment, SDKError> {
+        let url = format!("/comments/{}?fields={}", self.id, COMMENT_FIELDS);
+        let body = json!({ message_field(message): message });
+
+        let response = self.client.put(&url, body).await?;
+        let comment: Comment = response.deserialize().await?;
+        Ok(comment)
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!("/comments/{}", self.id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+}
+
+/// Box only notifies users mentioned with `@[id:name]` when the message is
+/// sent as `tagged_message`.
+pub(crate) fn message_field(message: &str) -> &'static str {
+    if message.contains("@[") {
+        "tagged_message"
+    } else {
+        "message"
+    }
+}
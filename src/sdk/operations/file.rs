@@ -1,8 +1,59 @@
-use crate::sdk::models::File;
-use crate::sdk::{Client, SDKError};
-use serde::Serialize;
-use std::path::Path;
-use tokio::io::AsyncWriteExt;
+use super::comment::COMMENT_FIELDS;
+use super::{check_name_conflict, copy_item, numbered_name, ParentReference};
+use crate::sdk::models::{
+    ClientError, Collection, Comment, File, Representation, RepresentationState, SharedLinkAccess,
+    Task, UploadPreflight, UploadSession,
+};
+use crate::sdk::network::upload_url;
+use crate::sdk::{sha1_file, Body, Client, HTTPMethod, MultipartBody, SDKError, UploadState};
+use chrono::{DateTime, FixedOffset, Utc};
+use futures::stream::{self, StreamExt, TryStreamExt};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha1::{Digest, Sha1};
+use std::io::SeekFrom;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use tokio::fs;
+use tokio::io::{self as io, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::codec::{BytesCodec, FramedRead};
+
+/// Every field of the `File` model. `lock` isn't sent unless asked for, so
+/// `get` asks for all of them.
+const FILE_FIELDS: &[&str] = &[
+    "etag",
+    "name",
+    "sequence_id",
+    "allowed_invitee_roles",
+    "collections",
+    "content_created_at",
+    "content_modified_at",
+    "created_at",
+    "created_by",
+    "description",
+    "file_version",
+    "has_collaborations",
+    "is_externally_owned",
+    "item_status",
+    "lock",
+    "modified_at",
+    "modified_by",
+    "owned_by",
+    "parent",
+    "path_collection",
+    "purged_at",
+    "sha1",
+    "shared_link",
+    "size",
+    "tags",
+    "trashed_at",
+];
+
+/// How long to wait between checks on a representation Box is generating, and
+/// how many checks to make before giving up on it.
+const REPRESENTATION_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_REPRESENTATION_POLLS: u32 = 300;
 
 pub struct FileOperation<'a> {
     id: &'a str,
@@ -15,7 +66,18 @@ impl<'a> FileOperation<'a> {
     }
 
     pub async fn get(&mut self) -> Result<File, SDKError> {
-        let url = format!("https://api.box.com/2.0/files/{}", self.id);
+        self.get_fields(FILE_FIELDS).await
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!("/files/{}", self.id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+
+    /// Gets only the given fields of the file, leaving the others unset.
+    pub async fn get_fields(&mut self, fields: &[&str]) -> Result<File, SDKError> {
+        let url = format!("/files/{}?fields={}", self.id, fields.join(","));
         let response = self.client.get(&url).await?;
 
         let file: File = response.deserialize().await?;
@@ -23,44 +85,523 @@ impl<'a> FileOperation<'a> {
         Ok(file)
     }
 
-    pub async fn delete(&mut self) -> Result<(), SDKError> {
-        let url = format!("https://api.box.com/2.0/files/{}", self.id);
-        self.client.delete(&url).await?;
-        Ok(())
+    async fn sha1(&mut self) -> Result<Option<String>, SDKError> {
+        let file = self.get_fields(&["sha1"]).await?;
+        Ok(file.sha1().map(str::to_owned))
     }
 
+    /// Works out where a download to `path` should be saved. A directory means
+    /// the file is saved inside it under its Box name, and `policy` decides what
+    /// happens when something is already there.
+    pub async fn destination(
+        &mut self,
+        path: &Path,
+        policy: ExistingFilePolicy,
+    ) -> Result<Destination, SDKError> {
+        let mut info = None;
+        let mut path = path.to_owned();
+        if fs::metadata(&path)
+            .await
+            .map(|m| m.is_dir())
+            .unwrap_or(false)
+        {
+            let file = self.get_fields(&["name", "content_modified_at"]).await?;
+            path = path.join(file.name().unwrap_or(self.id));
+            info = Some(file);
+        }
+
+        let metadata = match fs::metadata(&path).await {
+            Ok(metadata) => metadata,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Destination::Download(path))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        match policy {
+            ExistingFilePolicy::Fail => Err(already_exists(&path)),
+            ExistingFilePolicy::Overwrite => Ok(Destination::Download(path)),
+            ExistingFilePolicy::Skip => Ok(Destination::Skip(path)),
+            ExistingFilePolicy::Rename => Ok(Destination::Download(renamed_path(&path).await)),
+            ExistingFilePolicy::IfNewer => {
+                let info = match info {
+                    Some(info) => info,
+                    None => self.get_fields(&["name", "content_modified_at"]).await?,
+                };
+                let modified = metadata.modified()?;
+                match info.content_modified_at() {
+                    Some(remote) if remote <= DateTime::<Utc>::from(modified) => {
+                        Ok(Destination::Skip(path))
+                    }
+                    _ => Ok(Destination::Download(path)),
+                }
+            }
+        }
+    }
+
+    /// Downloads the file to `path`, checking the content against the file's
+    /// SHA1. Content is written to a `.part` file next to `path` first, so an
+    /// interrupted download picks up where it left off the next time. Anything
+    /// already at `path` is replaced once the download is complete.
     pub async fn download(&mut self, path: &Path) -> Result<(), SDKError> {
-        let url = format!("https://api.box.com/2.0/files/{}/content", self.id);
+        self.download_range_inner(path, None).await
+    }
 
+    /// Writes the content of the file to `writer` as it is downloaded. The
+    /// content can't be taken back once written, so a SHA1 mismatch is only
+    /// reported after the fact.
+    pub async fn download_to<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+    ) -> Result<(), SDKError> {
+        let expected = self.sha1().await?;
+        let url = format!("/files/{}/content", self.id);
         let mut response = self.client.get(&url).await?;
 
-        let mut file = tokio::fs::OpenOptions::new()
-            .create_new(true)
-            .write(true)
-            .open(path)
-            .await?;
-
+        let mut hasher = Sha1::new();
         while let Some(bytes) = response.chunk().await? {
-            file.write_all(&bytes).await?;
+            hasher.update(&bytes);
+            writer.write_all(&bytes).await?;
         }
+        writer.flush().await?;
 
-        Ok(())
+        let actual = format!("{:x}", hasher.finalize());
+        match expected {
+            Some(expected) if expected != actual => {
+                Err(SDKError::StreamIntegrity { expected, actual })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Downloads bytes `start` through `end` (inclusive, or to the end of the
+    /// file if `end` is `None`) of the file to `path`.
+    pub async fn download_range(
+        &mut self,
+        path: &Path,
+        start: u64,
+        end: Option<u64>,
+    ) -> Result<(), SDKError> {
+        self.download_range_inner(path, Some((start, end))).await
+    }
+
+    async fn download_range_inner(
+        &mut self,
+        path: &Path,
+        range: Option<(u64, Option<u64>)>,
+    ) -> Result<(), SDKError> {
+        // Only a whole file can be checked against its SHA1
+        let expected = match range {
+            Some(_) => None,
+            None => self.sha1().await?,
+        };
+
+        let url = format!("/files/{}/content", self.id);
+        download_through_part_file(
+            self.client,
+            &url,
+            path,
+            &part_path(path, range),
+            range,
+            expected,
+        )
+        .await
+    }
+
+    /// Downloads the file to `path` over several connections at once, each
+    /// fetching one range of the file into its place in a preallocated `.part`
+    /// file. The part size and number of connections are set on the [`Client`].
+    /// Files whose size Box doesn't report are downloaded over one connection.
+    pub async fn download_parallel(&mut self, path: &Path) -> Result<(), SDKError> {
+        let info = self.get_fields(&["size", "sha1"]).await?;
+        let size = match info.size() {
+            Some(size) => size,
+            None => return self.download_range_inner(path, None).await,
+        };
+
+        // Kept apart from the part file of a single stream download, which a
+        // preallocated file would look like a finished copy of
+        let part_path = part_path(path, None).with_extension("parallel.part");
+        fs::File::create(&part_path).await?.set_len(size).await?;
+
+        let result = self.download_ranges(&part_path, size).await;
+        let actual = match result {
+            Ok(()) => sha1_file(&part_path).await,
+            Err(err) => Err(err),
+        };
+        let actual = match actual {
+            Ok(actual) => actual,
+            Err(err) => {
+                // A partly filled preallocated file can't be resumed from
+                let _ = fs::remove_file(&part_path).await;
+                return Err(err);
+            }
+        };
+
+        match info.sha1() {
+            Some(expected) if expected != actual => {
+                fs::remove_file(&part_path).await?;
+                Err(SDKError::Integrity {
+                    path: path.to_owned(),
+                    expected: expected.to_owned(),
+                    actual,
+                })
+            }
+            _ => {
+                fs::rename(&part_path, path).await?;
+                Ok(())
+            }
+        }
+    }
+
+    async fn download_ranges(&mut self, part_path: &Path, size: u64) -> Result<(), SDKError> {
+        let url = format!("/files/{}/content", self.id);
+        let token = self.client.access_token().await?;
+        let part_size = self.client.download_part_size();
+        let concurrency = self.client.download_concurrency();
+        let client: &Client = self.client;
+
+        let ranges = (0..size)
+            .step_by(part_size as usize)
+            .map(|start| (start, (start + part_size).min(size) - 1));
+        stream::iter(ranges)
+            .map(|(start, end)| {
+                let request = client
+                    .network()
+                    .start_request(HTTPMethod::GET, &url)
+                    .with_header("Authorization", &format!("Bearer {}", token))
+                    .with_header("Range", &format!("bytes={}-{}", start, end));
+                async move {
+                    let mut response = client.network().send_request(request).await?;
+                    if response.status() != StatusCode::PARTIAL_CONTENT {
+                        return Err(SDKError::RangeIgnored);
+                    }
+
+                    let mut file = fs::OpenOptions::new().write(true).open(part_path).await?;
+                    file.seek(SeekFrom::Start(start)).await?;
+                    while let Some(bytes) = response.chunk().await? {
+                        file.write_all(&bytes).await?;
+                    }
+                    file.flush().await?;
+                    Ok(())
+                }
+            })
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await
+    }
+
+    /// Checks that the file at `path` has the same content as this file.
+    pub async fn verify(&mut self, path: &Path) -> Result<(), SDKError> {
+        let expected = self
+            .sha1()
+            .await?
+            .ok_or_else(|| SDKError::MissingSha1(self.id.to_owned()))?;
+        let actual = sha1_file(path).await?;
+
+        if expected == actual {
+            Ok(())
+        } else {
+            Err(SDKError::Integrity {
+                path: path.to_owned(),
+                expected,
+                actual,
+            })
+        }
+    }
+
+    /// Replaces the content of the file with the file at `path`, optionally
+    /// renaming it. When `if_match` is set, the upload fails if the file's etag
+    /// has changed.
+    pub async fn upload_version(
+        &mut self,
+        path: &Path,
+        if_match: Option<&str>,
+        name: Option<&str>,
+    ) -> Result<File, SDKError> {
+        let file_size = fs::metadata(path).await?.len();
+        if file_size > self.client.chunked_upload_threshold() {
+            return self
+                .upload_version_chunked(path, if_match, name, file_size)
+                .await;
+        }
+
+        let sha1 = sha1_file(path).await?;
+        let file = fs::File::open(path).await?;
+        let stream = FramedRead::new(file, BytesCodec::new());
+
+        let attributes_json = match name {
+            Some(name) => json!({ "name": name }),
+            None => json!({}),
+        }
+        .to_string();
+
+        let form = MultipartBody::new()
+            .with_text_part("attributes", &attributes_json)
+            .with_stream_part("file", stream);
+
+        let url = upload_url(&format!("/files/{}/content", self.id));
+        let mut request = self
+            .client
+            .network()
+            .start_request(HTTPMethod::POST, &url)
+            .with_header("Content-MD5", &sha1)
+            .with_body(Body::Multipart(form));
+        if let Some(etag) = if_match {
+            request = request.with_header("If-Match", etag);
+        }
+
+        let response = self.client.send(request).await?;
+        let data: Collection<File> = response.deserialize().await?;
+        data.entries
+            .into_iter()
+            .next()
+            .ok_or_else(|| SDKError::NothingUploaded(path.display().to_string()))
+    }
+
+    /// Checks whether a new version of `size` bytes, optionally renaming the
+    /// file to `name`, could be uploaded, without sending any content.
+    pub async fn preflight_upload_version(
+        &mut self,
+        name: Option<&str>,
+        size: u64,
+    ) -> Result<UploadPreflight, SDKError> {
+        let url = format!("/files/{}/content", self.id);
+        let body = match name {
+            Some(name) => json!({ "name": name, "size": size }),
+            None => json!({ "size": size }),
+        };
+
+        match self.client.options(&url, body).await {
+            Ok(response) => Ok(response.deserialize().await?),
+            Err(SDKError::APIError { response }) => {
+                let error: ClientError = response.deserialize().await?;
+                Err(SDKError::UploadRejected(error))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    async fn upload_version_chunked(
+        &mut self,
+        path: &Path,
+        if_match: Option<&str>,
+        name: Option<&str>,
+        file_size: u64,
+    ) -> Result<File, SDKError> {
+        self.preflight_upload_version(name, file_size).await?;
+        let url = upload_url(&format!("/files/{}/upload_sessions", self.id));
+        let body = match name {
+            Some(name) => json!({ "file_size": file_size, "file_name": name }),
+            None => json!({ "file_size": file_size }),
+        };
+
+        let response = self.client.post(&url, body).await?;
+        let session: UploadSession = response.deserialize().await?;
+
+        let mut state = UploadState::new(path, &session, &fs::metadata(path).await?);
+        if let Some(etag) = if_match {
+            state = state.with_if_match(etag);
+        }
+        state.save().await?;
+
+        self.client.finish_chunked_upload(path, state).await
     }
 
     pub async fn update(&mut self, updates: FileUpdates) -> Result<File, SDKError> {
-        let url = format!("https://api.box.com/2.0/files/{}", self.id);
+        let url = format!("/files/{}", self.id);
+
+        let response = check_name_conflict(self.client.put(&url, updates).await).await?;
+        let file: File = response.deserialize().await?;
+        Ok(file)
+    }
+
+    /// Locks the file so only the current user can change it, until
+    /// `expires_at` or until it's unlocked. With `prevent_download`, only the
+    /// current user can download it either.
+    pub async fn lock(
+        &mut self,
+        expires_at: Option<DateTime<FixedOffset>>,
+        prevent_download: bool,
+    ) -> Result<File, SDKError> {
+        let url = format!("/files/{}?fields=name,lock", self.id);
+        let updates = FileUpdates::new().lock(expires_at, prevent_download);
 
         let response = self.client.put(&url, updates).await?;
         let file: File = response.deserialize().await?;
         Ok(file)
     }
+
+    pub async fn unlock(&mut self) -> Result<File, SDKError> {
+        let url = format!("/files/{}?fields=name,lock", self.id);
+
+        let response = self.client.put(&url, FileUpdates::new().unlock()).await?;
+        let file: File = response.deserialize().await?;
+        Ok(file)
+    }
+
+    /// Lists all of the comments on the file, replies included. See
+    /// `Comment::threads` to group them into conversations.
+    pub async fn comments(&mut self) -> Result<Vec<Comment>, SDKError> {
+        let mut comments = Vec::new();
+        loop {
+            let url = format!(
+                "/files/{}/comments?fields={}&offset={}&limit=1000",
+                self.id,
+                COMMENT_FIELDS,
+                comments.len()
+            );
+            let response = self.client.get(&url).await?;
+            let page: Collection<Comment> = response.deserialize().await?;
+
+            let last_page = page.entries.is_empty();
+            comments.extend(page.entries);
+            if last_page || comments.len() as u64 >= page.total_count {
+                return Ok(comments);
+            }
+        }
+    }
+
+    pub async fn tasks(&mut self) -> Result<Collection<Task>, SDKError> {
+        let url = format!("/files/{}/tasks", self.id);
+        let response = self.client.get(&url).await?;
+
+        let tasks: Collection<Task> = response.deserialize().await?;
+
+        Ok(tasks)
+    }
+
+    /// Lists the representations Box can generate for the file. `hints` picks
+    /// which ones, in `x-rep-hints` syntax like `[jpg?dimensions=32x32][pdf]`.
+    pub async fn representations(
+        &mut self,
+        hints: Option<&str>,
+    ) -> Result<Vec<Representation>, SDKError> {
+        let url = format!("/files/{}?fields=representations", self.id);
+        let mut request = self.client.network().start_request(HTTPMethod::GET, &url);
+        if let Some(hints) = hints {
+            request = request.with_header("X-Rep-Hints", hints);
+        }
+
+        let response = self.client.send(request).await?;
+        let file: FileRepresentations = response.deserialize().await?;
+        Ok(file.representations.entries)
+    }
+
+    /// Gets one representation of the file, such as `png`, `pdf` or
+    /// `extracted_text`, optionally at `dimensions` like `320x320`. Waits for Box
+    /// to generate it if it isn't ready yet.
+    pub async fn representation(
+        &mut self,
+        representation: &str,
+        dimensions: Option<&str>,
+    ) -> Result<Representation, SDKError> {
+        let hint = match dimensions {
+            Some(dimensions) => format!("[{}?dimensions={}]", representation, dimensions),
+            None => format!("[{}]", representation),
+        };
+        let unavailable = || SDKError::RepresentationUnavailable(hint.clone());
+        let mut rep = self
+            .representations(Some(&hint))
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(unavailable)?;
+
+        for _ in 0..MAX_REPRESENTATION_POLLS {
+            match rep.status().map(|status| status.state()) {
+                Some(RepresentationState::Success) | Some(RepresentationState::Viewable) => {
+                    return Ok(rep)
+                }
+                Some(RepresentationState::Pending) | Some(RepresentationState::NotStarted) => {
+                    tokio::time::delay_for(REPRESENTATION_POLL_INTERVAL).await
+                }
+                _ => return Err(unavailable()),
+            }
+
+            // Fetching the info URL starts generation and reports how it's going
+            let url = rep.info().ok_or_else(unavailable)?.url().to_owned();
+            let response = self.client.get(&url).await?;
+            rep = response.deserialize().await?;
+        }
+
+        Err(SDKError::RepresentationTimedOut(
+            hint,
+            REPRESENTATION_POLL_INTERVAL * MAX_REPRESENTATION_POLLS,
+        ))
+    }
+
+    /// Downloads one asset of a generated representation to `path`. Use an
+    /// empty `asset_path` for representations made of a single file.
+    pub async fn download_representation(
+        &mut self,
+        representation: &Representation,
+        asset_path: &str,
+        path: &Path,
+    ) -> Result<(), SDKError> {
+        let content = representation.content().ok_or_else(|| {
+            SDKError::RepresentationUnavailable(representation.representation().to_owned())
+        })?;
+        let mut response = self.client.get(&content.url(asset_path)).await?;
+
+        let mut file = fs::File::create(path).await?;
+        while let Some(bytes) = response.chunk().await? {
+            file.write_all(&bytes).await?;
+        }
+        file.flush().await?;
+        Ok(())
+    }
+
+    /// Copies the file into the folder `folder_id`, optionally under a new name
+    /// or copying an older version. Fails with `SDKError::NameConflict` if the
+    /// folder already has an item with that name, unless `rename` is set, in
+    /// which case a number is added to the name.
+    pub async fn copy(
+        &mut self,
+        folder_id: &str,
+        name: Option<&str>,
+        version: Option<&str>,
+        rename: bool,
+    ) -> Result<File, SDKError> {
+        let url = format!("/files/{}/copy", self.id);
+        let mut body = json!({ "parent": ParentReference::new(folder_id) });
+        if let Some(name) = name {
+            body["name"] = json!(name);
+        }
+        if let Some(version) = version {
+            body["version"] = json!(version);
+        }
+
+        copy_item(self.client, &url, body, rename).await
+    }
+
+    pub async fn move_to(&mut self, folder_id: &str) -> Result<File, SDKError> {
+        self.update(FileUpdates::new().parent(folder_id)).await
+    }
 }
 
-#[derive(Serialize)]
+/// Changes to make to a file. Fields that aren't set are left as they are;
+/// the `clear_` and `remove_` setters send `null` to unset a field.
+#[derive(Serialize, Default)]
 pub struct FileUpdates {
-    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     name: Option<String>,
-    // @TODO: Implement other fields
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<ParentReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tags: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    shared_link: Option<Option<SharedLinkUpdates>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock: Option<Option<Lock>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collections: Option<Vec<CollectionReference>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    disposition_at: Option<Option<DateTime<FixedOffset>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<FilePermissions>,
 }
 
 impl FileUpdates {
@@ -68,11 +609,23 @@ impl FileUpdates {
         FileUpdates {
             description: None,
             name: None,
+            parent: None,
+            tags: None,
+            shared_link: None,
+            lock: None,
+            collections: None,
+            disposition_at: None,
+            permissions: None,
         }
     }
 
     pub fn description(mut self, description: &str) -> Self {
-        self.description = Some(description.to_string());
+        self.description = Some(Some(description.to_string()));
+        self
+    }
+
+    pub fn clear_description(mut self) -> Self {
+        self.description = Some(None);
         self
     }
 
@@ -80,4 +633,334 @@ impl FileUpdates {
         self.name = Some(name.to_string());
         self
     }
+
+    pub fn parent(mut self, folder_id: &str) -> Self {
+        self.parent = Some(ParentReference::new(folder_id));
+        self
+    }
+
+    /// Replaces all of the file's tags.
+    pub fn tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+        self.tags = Some(tags.iter().map(|tag| tag.as_ref().to_string()).collect());
+        self
+    }
+
+    /// Creates a shared link for the file, or changes the existing one.
+    pub fn shared_link(mut self, shared_link: SharedLinkUpdates) -> Self {
+        self.shared_link = Some(Some(shared_link));
+        self
+    }
+
+    pub fn remove_shared_link(mut self) -> Self {
+        self.shared_link = Some(None);
+        self
+    }
+
+    /// Locks the file until `expires_at`, or until it's unlocked if that's
+    /// `None`. With `prevent_download`, only the lock's owner can download it.
+    pub fn lock(
+        mut self,
+        expires_at: Option<DateTime<FixedOffset>>,
+        prevent_download: bool,
+    ) -> Self {
+        self.lock = Some(Some(Lock {
+            access: "lock",
+            expires_at,
+            is_download_prevented: prevent_download,
+        }));
+        self
+    }
+
+    pub fn unlock(mut self) -> Self {
+        self.lock = Some(None);
+        self
+    }
+
+    /// Replaces the collections the file is in. An empty list removes it from
+    /// all of them.
+    pub fn collections<S: AsRef<str>>(mut self, collection_ids: &[S]) -> Self {
+        self.collections = Some(
+            collection_ids
+                .iter()
+                .map(|id| CollectionReference {
+                    id: id.as_ref().to_string(),
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Sets when a file under a retention policy can be disposed of.
+    pub fn disposition_at(mut self, disposition_at: DateTime<FixedOffset>) -> Self {
+        self.disposition_at = Some(Some(disposition_at));
+        self
+    }
+
+    pub fn clear_disposition_at(mut self) -> Self {
+        self.disposition_at = Some(None);
+        self
+    }
+
+    /// Sets who can download the file.
+    pub fn can_download(mut self, can_download: DownloadPermission) -> Self {
+        self.permissions = Some(FilePermissions { can_download });
+        self
+    }
+}
+
+/// Settings for a file's shared link. Fields that aren't set are left as they
+/// are.
+#[derive(Serialize, Default)]
+pub struct SharedLinkUpdates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    access: Option<SharedLinkAccess>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    unshared_at: Option<Option<DateTime<FixedOffset>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<SharedLinkPermissionUpdates>,
+}
+
+impl SharedLinkUpdates {
+    pub fn new() -> SharedLinkUpdates {
+        SharedLinkUpdates {
+            access: None,
+            password: None,
+            unshared_at: None,
+            permissions: None,
+        }
+    }
+
+    pub fn access(mut self, access: SharedLinkAccess) -> Self {
+        self.access = Some(access);
+        self
+    }
+
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(Some(password.to_string()));
+        self
+    }
+
+    pub fn clear_password(mut self) -> Self {
+        self.password = Some(None);
+        self
+    }
+
+    pub fn unshared_at(mut self, unshared_at: DateTime<FixedOffset>) -> Self {
+        self.unshared_at = Some(Some(unshared_at));
+        self
+    }
+
+    pub fn clear_unshared_at(mut self) -> Self {
+        self.unshared_at = Some(None);
+        self
+    }
+
+    pub fn can_download(mut self, can_download: bool) -> Self {
+        self.permissions = Some(SharedLinkPermissionUpdates { can_download });
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct SharedLinkPermissionUpdates {
+    can_download: bool,
+}
+
+#[derive(Serialize)]
+struct Lock {
+    access: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<FixedOffset>>,
+    is_download_prevented: bool,
+}
+
+#[derive(Serialize)]
+struct CollectionReference {
+    id: String,
+}
+
+#[derive(Serialize)]
+struct FilePermissions {
+    can_download: DownloadPermission,
+}
+
+/// Who can download a file, apart from its owners and editors.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DownloadPermission {
+    Open,
+    Company,
+}
+
+#[derive(Deserialize)]
+struct FileRepresentations {
+    representations: RepresentationEntries,
+}
+
+#[derive(Deserialize)]
+struct RepresentationEntries {
+    entries: Vec<Representation>,
+}
+
+/// What to do when a download's destination already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExistingFilePolicy {
+    Fail,
+    Overwrite,
+    Skip,
+    /// Save the download next to the existing file as `name (1).ext`.
+    Rename,
+    /// Replace the existing file only if the file in Box was modified after it.
+    IfNewer,
+}
+
+impl ExistingFilePolicy {
+    /// Where to save newly made content, like an archive Box builds on
+    /// request, given what's already at `path`. Such content is always newer,
+    /// so `IfNewer` replaces the file.
+    pub async fn destination(self, path: &Path) -> Result<Destination, SDKError> {
+        let path = path.to_owned();
+        match fs::metadata(&path).await {
+            Ok(_) => {}
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                return Ok(Destination::Download(path))
+            }
+            Err(err) => return Err(err.into()),
+        }
+
+        match self {
+            ExistingFilePolicy::Fail => Err(already_exists(&path)),
+            ExistingFilePolicy::Skip => Ok(Destination::Skip(path)),
+            ExistingFilePolicy::Rename => Ok(Destination::Download(renamed_path(&path).await)),
+            ExistingFilePolicy::Overwrite | ExistingFilePolicy::IfNewer => {
+                Ok(Destination::Download(path))
+            }
+        }
+    }
+}
+
+/// Where a download should be saved, once the path it was given has been
+/// resolved and checked against an `ExistingFilePolicy`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Destination {
+    /// Save the download here.
+    Download(PathBuf),
+    /// Keep the file already here and don't download anything.
+    Skip(PathBuf),
+}
+
+fn already_exists(path: &Path) -> SDKError {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("{} already exists", path.display()),
+    )
+    .into()
+}
+
+async fn renamed_path(path: &Path) -> PathBuf {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+
+    let mut n = 1;
+    loop {
+        let renamed = path.with_file_name(numbered_name(&name, n));
+        if fs::metadata(&renamed).await.is_err() {
+            return renamed;
+        }
+        n += 1;
+    }
+}
+
+/// Downloads the content at `url` to `path`, writing it to `part_path` first
+/// and picking up from whatever that file already holds. `path` is only
+/// replaced once the content is complete and matches `expected`, if given.
+pub(super) async fn download_through_part_file(
+    client: &mut Client,
+    url: &str,
+    path: &Path,
+    part_path: &Path,
+    range: Option<(u64, Option<u64>)>,
+    expected: Option<String>,
+) -> Result<(), SDKError> {
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .read(true)
+        .write(true)
+        .open(part_path)
+        .await?;
+
+    let mut hasher = Sha1::new();
+    let mut downloaded = 0;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+        downloaded += read as u64;
+    }
+
+    let (start, end) = range.unwrap_or((0, None));
+    let mut request = client.network().start_request(HTTPMethod::GET, url);
+    let ranged = start + downloaded > 0 || end.is_some();
+    if ranged {
+        let end = end.map(|end| end.to_string()).unwrap_or_default();
+        request = request.with_header("Range", &format!("bytes={}-{}", start + downloaded, end));
+    }
+
+    match client.send(request).await {
+        Ok(mut response) => {
+            if ranged && response.status() != StatusCode::PARTIAL_CONTENT {
+                // The whole file was sent back, so start over
+                if range.is_some() {
+                    return Err(SDKError::RangeIgnored);
+                }
+                file.set_len(0).await?;
+                file.seek(SeekFrom::Start(0)).await?;
+                hasher = Sha1::new();
+            }
+
+            while let Some(bytes) = response.chunk().await? {
+                hasher.update(&bytes);
+                file.write_all(&bytes).await?;
+            }
+        }
+        // The part file already holds everything that was asked for
+        Err(SDKError::APIError { response })
+            if downloaded > 0 && response.status() == StatusCode::RANGE_NOT_SATISFIABLE => {}
+        Err(err) => return Err(err),
+    }
+    drop(file);
+
+    let actual = format!("{:x}", hasher.finalize());
+    match expected {
+        Some(expected) if expected != actual => {
+            fs::remove_file(part_path).await?;
+            Err(SDKError::Integrity {
+                path: path.to_owned(),
+                expected,
+                actual,
+            })
+        }
+        _ => {
+            fs::rename(part_path, path).await?;
+            Ok(())
+        }
+    }
+}
+
+/// Where a download to `path` is kept until it finishes. Each range gets its
+/// own part file, so resuming never appends one range to another.
+pub(super) fn part_path(path: &Path, range: Option<(u64, Option<u64>)>) -> PathBuf {
+    let mut part_path = path.as_os_str().to_owned();
+    match range {
+        Some((start, Some(end))) => part_path.push(format!(".{}-{}", start, end)),
+        Some((start, None)) => part_path.push(format!(".{}-", start)),
+        None => {}
+    }
+    part_path.push(".part");
+    PathBuf::from(part_path)
 }
@@ -0,0 +1,86 @@
+use super::file::{download_through_part_file, part_path};
+use crate::sdk::models::{Collection, FileVersion};
+use crate::sdk::{Client, SDKError};
+use serde_json::json;
+use std::path::Path;
+use tokio::fs;
+use tokio::io;
+
+pub struct FileVersionOperation<'a> {
+    file_id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> FileVersionOperation<'a> {
+    pub fn new(file_id: &'a str, client: &'a mut Client) -> FileVersionOperation<'a> {
+        FileVersionOperation { file_id, client }
+    }
+
+    /// Lists the previous versions of the file. The current version isn't
+    /// included.
+    pub async fn list(&mut self) -> Result<Collection<FileVersion>, SDKError> {
+        let url = format!("/files/{}/versions", self.file_id);
+        let response = self.client.get(&url).await?;
+
+        let versions: Collection<FileVersion> = response.deserialize().await?;
+
+        Ok(versions)
+    }
+
+    pub async fn get(&mut self, id: &str) -> Result<FileVersion, SDKError> {
+        let url = format!("/files/{}/versions/{}", self.file_id, id);
+        let response = self.client.get(&url).await?;
+
+        let version: FileVersion = response.deserialize().await?;
+
+        Ok(version)
+    }
+
+    /// Downloads the content of version `id` to `path`, checking it against the
+    /// version's SHA1. Like a download of the current version, it goes through a
+    /// `.part` file that an interrupted download resumes from, and is deleted if
+    /// it doesn't match. Fails if something is already at `path`.
+    pub async fn download(&mut self, id: &str, path: &Path) -> Result<(), SDKError> {
+        if fs::metadata(path).await.is_ok() {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", path.display()),
+            )
+            .into());
+        }
+
+        let expected = self.get(id).await?.sha1().to_owned();
+        let url = format!("/files/{}/content?version={}", self.file_id, id);
+        // Kept apart from the part files of other versions of the file
+        let part_path = part_path(path, None).with_extension(format!("{}.part", id));
+        download_through_part_file(self.client, &url, path, &part_path, None, Some(expected)).await
+    }
+
+    /// Makes a copy of version `id` the current version of the file. Returns the
+    /// new version.
+    pub async fn promote(&mut self, id: &str) -> Result<FileVersion, SDKError> {
+        let url = format!("/files/{}/versions/current", self.file_id);
+        let body = json!({ "type": "file_version", "id": id });
+
+        let response = self.client.post(&url, body).await?;
+        let version: FileVersion = response.deserialize().await?;
+        Ok(version)
+    }
+
+    /// Moves version `id` to the trash.
+    pub async fn delete(&mut self, id: &str) -> Result<(), SDKError> {
+        let url = format!("/files/{}/versions/{}", self.file_id, id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+
+    /// Restores version `id` from the trash.
+    pub async fn restore(&mut self, id: &str) -> Result<FileVersion, SDKError> {
+        let url = format!("/files/{}/versions/{}", self.file_id, id);
+        let body = json!({ "trashed_at": null });
+
+        let response = self.client.put(&url, body).await?;
+        let version: FileVersion = response.deserialize().await?;
+        Ok(version)
+    }
+}
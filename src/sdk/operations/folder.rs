@@ -1,5 +1,9 @@
 use crate::sdk::models::{Collection, Folder, Item};
 use crate::sdk::{Client, Response, SDKError};
+use serde_json::json;
+use std::collections::HashMap;
+
+use super::{check_name_conflict, copy_item, ParentReference};
 
 pub struct FolderOperation<'a> {
     id: &'a str,
@@ -28,4 +32,117 @@ impl<'a> FolderOperation<'a> {
 
         Ok(items)
     }
+
+    /// Lists everything in the folder, however many requests that takes.
+    pub async fn get_all_items(&mut self) -> Result<Vec<Item>, SDKError> {
+        self.get_all_items_with_fields(&[]).await
+    }
+
+    /// Lists everything in the folder like `get_all_items`, returning only
+    /// the given fields of each item, which can include metadata fields like
+    /// `metadata.enterprise.contract`. No fields means the default set.
+    pub async fn get_all_items_with_fields(
+        &mut self,
+        fields: &[&str],
+    ) -> Result<Vec<Item>, SDKError> {
+        let fields = match fields {
+            [] => String::new(),
+            fields => format!("&fields={}", fields.join(",")),
+        };
+        let mut items = Vec::new();
+        loop {
+            let url = format!(
+                "/folders/{}/items?limit=1000&offset={}{}",
+                self.id,
+                items.len(),
+                fields
+            );
+            let response = self.client.get(&url).await?;
+            let page: Collection<Item> = response.deserialize().await?;
+
+            let last_page = page.entries.is_empty();
+            items.extend(page.entries);
+            if last_page || items.len() as u64 >= page.total_count {
+                return Ok(items);
+            }
+        }
+    }
+
+    /// Finds the item at `path`, a `/`-separated list of names starting in
+    /// this folder. Fails with `SDKError::PathNotFound` if there's no such item.
+    pub async fn item_at(&mut self, path: &str) -> Result<Item, SDKError> {
+        self.item_at_cached(path, &mut FolderListings::new()).await
+    }
+
+    /// Finds the item at `path` like `item_at`, listing each folder on the
+    /// way only if it isn't already in `listings`.
+    pub async fn item_at_cached(
+        &mut self,
+        path: &str,
+        listings: &mut FolderListings,
+    ) -> Result<Item, SDKError> {
+        let not_found = || SDKError::PathNotFound(path.to_owned());
+        let mut names = path.split('/').filter(|name| !name.is_empty()).peekable();
+        let mut folder_id = self.id.to_owned();
+        loop {
+            let name = names.next().ok_or_else(not_found)?;
+            if !listings.folders.contains_key(&folder_id) {
+                let items = self.client.folder(&folder_id).get_all_items().await?;
+                listings.folders.insert(folder_id.clone(), items);
+            }
+            let item = listings.folders[&folder_id]
+                .iter()
+                .find(|item| item.name() == Some(name))
+                .cloned()
+                .ok_or_else(not_found)?;
+
+            match item {
+                _ if names.peek().is_none() => return Ok(item),
+                Item::Folder(folder) => folder_id = folder.id().to_owned(),
+                _ => return Err(not_found()),
+            }
+        }
+    }
+
+    /// Copies the folder and everything in it into the folder `folder_id`,
+    /// optionally under a new name. Fails with `SDKError::NameConflict` if the
+    /// destination already has an item with that name, unless `rename` is set,
+    /// in which case a number is added to the name.
+    pub async fn copy(
+        &mut self,
+        folder_id: &str,
+        name: Option<&str>,
+        rename: bool,
+    ) -> Result<Folder, SDKError> {
+        let url = format!("/folders/{}/copy", self.id);
+        let mut body = json!({ "parent": ParentReference::new(folder_id) });
+        if let Some(name) = name {
+            body["name"] = json!(name);
+        }
+
+        copy_item(self.client, &url, body, rename).await
+    }
+
+    pub async fn move_to(&mut self, folder_id: &str) -> Result<Folder, SDKError> {
+        let url = format!("/folders/{}", self.id);
+        let body = json!({ "parent": ParentReference::new(folder_id) });
+
+        let response = check_name_conflict(self.client.put(&url, body).await).await?;
+        let folder: Folder = response.deserialize().await?;
+        Ok(folder)
+    }
+}
+
+/// The contents of folders already listed, so that looking up many paths
+/// lists each folder only once. Changes made since a folder was listed
+/// aren't seen.
+#[derive(Default)]
+pub struct FolderListings {
+    folders: HashMap<String, Vec<Item>>,
+}
+
+impl FolderListings {
+    pub fn new() -> FolderListings {
+        Default::default()
+    }
 }
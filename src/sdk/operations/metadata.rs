@@ -0,0 +1,211 @@
+use crate::sdk::models::Metadata;
+use crate::sdk::{Body, Client, HTTPMethod, SDKError};
+use http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Deserialize)]
+struct MetadataEntries {
+    entries: Vec<Metadata>,
+}
+
+/// Metadata instances on a file or folder, each identified by the scope and
+/// key of its template.
+pub struct MetadataOperation<'a> {
+    items: &'static str,
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> MetadataOperation<'a> {
+    pub fn for_file(id: &'a str, client: &'a mut Client) -> MetadataOperation<'a> {
+        MetadataOperation {
+            items: "files",
+            id,
+            client,
+        }
+    }
+
+    pub fn for_folder(id: &'a str, client: &'a mut Client) -> MetadataOperation<'a> {
+        MetadataOperation {
+            items: "folders",
+            id,
+            client,
+        }
+    }
+
+    fn url(&self, scope: &str, template: &str) -> String {
+        format!(
+            "/{}/{}/metadata/{}/{}",
+            self.items, self.id, scope, template
+        )
+    }
+
+    pub async fn list(&mut self) -> Result<Vec<Metadata>, SDKError> {
+        let url = format!("/{}/{}/metadata", self.items, self.id);
+        let response = self.client.get(&url).await?;
+
+        let metadata: MetadataEntries = response.deserialize().await?;
+
+        Ok(metadata.entries)
+    }
+
+    pub async fn get(&mut self, scope: &str, template: &str) -> Result<Metadata, SDKError> {
+        let url = self.url(scope, template);
+        let response = self.client.get(&url).await?;
+
+        let metadata: Metadata = response.deserialize().await?;
+
+        Ok(metadata)
+    }
+
+    /// Applies the template to the item with the given field values.
+    pub async fn create(
+        &mut self,
+        scope: &str,
+        template: &str,
+        fields: Map<String, Value>,
+    ) -> Result<Metadata, SDKError> {
+        let url = self.url(scope, template);
+        let response = self.client.post(&url, fields).await?;
+
+        let metadata: Metadata = response.deserialize().await?;
+
+        Ok(metadata)
+    }
+
+    /// Applies the template with the given field values, or sets them on the
+    /// existing instance if the item already has one. Fields that aren't given
+    /// keep their values, and fields given as `null` are left out of a new
+    /// instance and removed from an existing one.
+    pub async fn upsert(
+        &mut self,
+        scope: &str,
+        template: &str,
+        fields: Map<String, Value>,
+    ) -> Result<Metadata, SDKError> {
+        let url = self.url(scope, template);
+        let values: Map<String, Value> = fields
+            .iter()
+            .filter(|(_, value)| !value.is_null())
+            .map(|(field, value)| (field.clone(), value.clone()))
+            .collect();
+        match self.client.post(&url, &values).await {
+            Err(SDKError::APIError { response }) if response.status() == StatusCode::CONFLICT => {
+                let existing = self.get(scope, template).await?;
+                let updates = MetadataUpdates::setting(&existing, fields);
+                self.update(scope, template, updates).await
+            }
+            result => {
+                let metadata: Metadata = result?.deserialize().await?;
+                Ok(metadata)
+            }
+        }
+    }
+
+    /// Changes the instance's field values. The operations are applied in
+    /// order, and none are applied if any of them fails.
+    pub async fn update(
+        &mut self,
+        scope: &str,
+        template: &str,
+        updates: MetadataUpdates,
+    ) -> Result<Metadata, SDKError> {
+        let url = self.url(scope, template);
+        let request = self
+            .client
+            .network()
+            .start_request(HTTPMethod::PUT, &url)
+            .with_header("Content-Type", "application/json-patch+json")
+            .with_body(Body::JSON(serde_json::to_value(updates)?));
+        let response = self.client.send(request).await?;
+
+        let metadata: Metadata = response.deserialize().await?;
+
+        Ok(metadata)
+    }
+
+    pub async fn delete(&mut self, scope: &str, template: &str) -> Result<(), SDKError> {
+        let url = self.url(scope, template);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+}
+
+/// A JSON-Patch operation on a metadata instance. Paths are JSON pointers
+/// like `/amount`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum MetadataPatch {
+    Add { path: String, value: Value },
+    Replace { path: String, value: Value },
+    Remove { path: String },
+    Test { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+}
+
+#[derive(Serialize, Default)]
+#[serde(transparent)]
+pub struct MetadataUpdates {
+    operations: Vec<MetadataPatch>,
+}
+
+impl MetadataUpdates {
+    pub fn new() -> MetadataUpdates {
+        Default::default()
+    }
+
+    /// Sets `field` to `value`, whether or not it already has a value.
+    pub fn add(self, field: &str, value: Value) -> Self {
+        self.operation(MetadataPatch::Add {
+            path: pointer(field),
+            value,
+        })
+    }
+
+    /// Sets `field` to `value`, failing if it doesn't have a value yet.
+    pub fn replace(self, field: &str, value: Value) -> Self {
+        self.operation(MetadataPatch::Replace {
+            path: pointer(field),
+            value,
+        })
+    }
+
+    pub fn remove(self, field: &str) -> Self {
+        self.operation(MetadataPatch::Remove {
+            path: pointer(field),
+        })
+    }
+
+    /// Makes the whole update fail unless `field` is `value`.
+    pub fn test(self, field: &str, value: Value) -> Self {
+        self.operation(MetadataPatch::Test {
+            path: pointer(field),
+            value,
+        })
+    }
+
+    /// The updates that set `fields` on the `existing` instance, as `upsert`
+    /// does: fields with a value are added and `null` fields that the
+    /// instance has are removed.
+    pub fn setting(existing: &Metadata, fields: Map<String, Value>) -> Self {
+        fields.into_iter().fold(
+            MetadataUpdates::new(),
+            |updates, (field, value)| match value {
+                Value::Null if existing.extra().contains_key(&field) => updates.remove(&field),
+                Value::Null => updates,
+                value => updates.add(&field, value),
+            },
+        )
+    }
+
+    pub fn operation(mut self, operation: MetadataPatch) -> Self {
+        self.operations.push(operation);
+        self
+    }
+}
+
+fn pointer(field: &str) -> String {
+    format!("/{}", field.replace('~', "~0").replace('/', "~1"))
+}
@@ -0,0 +1,91 @@
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+#[derive(Serialize, Debug, Clone, Copy)]
+pub enum SortDirection {
+    #[serde(rename = "asc")]
+    Ascending,
+    #[serde(rename = "desc")]
+    Descending,
+}
+
+#[derive(Serialize, Debug, Clone)]
+struct QueryOrder {
+    field_key: String,
+    direction: SortDirection,
+}
+
+/// A search for files and folders by their metadata, like
+/// `amount > :min` over the `enterprise_12345.contract` template.
+#[derive(Serialize, Debug, Clone)]
+pub struct MetadataQuery {
+    from: String,
+    ancestor_folder_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    query: Option<String>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    query_params: Map<String, Value>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    order_by: Vec<QueryOrder>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    limit: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    marker: Option<String>,
+}
+
+impl MetadataQuery {
+    /// Searches the items in `ancestor_folder_id` and its subfolders that
+    /// have the template `from`, given as `scope.templateKey`.
+    pub fn new(from: &str, ancestor_folder_id: &str) -> MetadataQuery {
+        MetadataQuery {
+            from: from.to_string(),
+            ancestor_folder_id: ancestor_folder_id.to_string(),
+            query: None,
+            query_params: Map::new(),
+            order_by: Vec::new(),
+            fields: Vec::new(),
+            limit: None,
+            marker: None,
+        }
+    }
+
+    /// Filters the items with a condition on the template's fields, using
+    /// `:name` for values given with `param`.
+    pub fn query(mut self, query: &str) -> Self {
+        self.query = Some(query.to_string());
+        self
+    }
+
+    pub fn param(mut self, name: &str, value: Value) -> Self {
+        self.query_params.insert(name.to_string(), value);
+        self
+    }
+
+    pub fn order_by(mut self, field_key: &str, direction: SortDirection) -> Self {
+        self.order_by.push(QueryOrder {
+            field_key: field_key.to_string(),
+            direction,
+        });
+        self
+    }
+
+    /// Sets the fields returned for each item, such as `name` or
+    /// `metadata.enterprise_12345.contract.amount`.
+    pub fn fields(mut self, fields: &[&str]) -> Self {
+        self.fields = fields.iter().map(|field| field.to_string()).collect();
+        self
+    }
+
+    /// Sets how many items are fetched in each request.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub(crate) fn marker(mut self, marker: String) -> Self {
+        self.marker = Some(marker);
+        self
+    }
+}
@@ -0,0 +1,320 @@
+use crate::sdk::models::{MarkerCollection, MetadataFieldType, MetadataTemplate};
+use crate::sdk::{Client, SDKError};
+use serde::{Deserialize, Serialize};
+use url::form_urlencoded;
+
+pub struct MetadataTemplateOperation<'a> {
+    scope: &'a str,
+    template_key: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> MetadataTemplateOperation<'a> {
+    pub fn new(
+        scope: &'a str,
+        template_key: &'a str,
+        client: &'a mut Client,
+    ) -> MetadataTemplateOperation<'a> {
+        MetadataTemplateOperation {
+            scope,
+            template_key,
+            client,
+        }
+    }
+
+    pub async fn get(&mut self) -> Result<MetadataTemplate, SDKError> {
+        let url = format!(
+            "/metadata_templates/{}/{}/schema",
+            self.scope, self.template_key
+        );
+        let response = self.client.get(&url).await?;
+
+        let template: MetadataTemplate = response.deserialize().await?;
+
+        Ok(template)
+    }
+
+    /// Changes the template's fields. The changes are applied in order, and
+    /// none are applied if any of them fails.
+    pub async fn update(
+        &mut self,
+        updates: MetadataTemplateUpdates,
+    ) -> Result<MetadataTemplate, SDKError> {
+        let url = format!(
+            "/metadata_templates/{}/{}/schema",
+            self.scope, self.template_key
+        );
+        let response = self.client.put(&url, updates).await?;
+
+        let template: MetadataTemplate = response.deserialize().await?;
+
+        Ok(template)
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!(
+            "/metadata_templates/{}/{}/schema",
+            self.scope, self.template_key
+        );
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+}
+
+pub struct MetadataTemplatesOperation<'a> {
+    client: &'a mut Client,
+}
+
+impl<'a> MetadataTemplatesOperation<'a> {
+    pub fn new(client: &'a mut Client) -> MetadataTemplatesOperation<'a> {
+        MetadataTemplatesOperation { client }
+    }
+
+    /// Lists every template in `scope`, which is either `enterprise` or
+    /// `global`.
+    pub async fn list(&mut self, scope: &str) -> Result<Vec<MetadataTemplate>, SDKError> {
+        let mut templates = Vec::new();
+        let mut marker: Option<String> = None;
+        loop {
+            let mut query = form_urlencoded::Serializer::new(String::new());
+            query.append_pair("limit", "100");
+            if let Some(marker) = &marker {
+                query.append_pair("marker", marker);
+            }
+            let url = format!("/metadata_templates/{}?{}", scope, query.finish());
+            let response = self.client.get(&url).await?;
+            let page: MarkerCollection<MetadataTemplate> = response.deserialize().await?;
+
+            templates.extend(page.entries);
+            marker = page.next_marker.filter(|marker| !marker.is_empty());
+            if marker.is_none() {
+                return Ok(templates);
+            }
+        }
+    }
+}
+
+/// A template definition, which can be built in code or read from a file.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMetadataTemplate {
+    #[serde(default = "enterprise_scope")]
+    scope: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template_key: Option<String>,
+    display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden: Option<bool>,
+    #[serde(default)]
+    fields: Vec<NewMetadataField>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    copy_instance_on_item_copy: Option<bool>,
+}
+
+fn enterprise_scope() -> String {
+    "enterprise".to_string()
+}
+
+impl NewMetadataTemplate {
+    /// Starts a template in the enterprise scope. Box derives the template key
+    /// from `display_name` unless one is set.
+    pub fn new(display_name: &str) -> NewMetadataTemplate {
+        NewMetadataTemplate {
+            scope: enterprise_scope(),
+            template_key: None,
+            display_name: display_name.to_string(),
+            hidden: None,
+            fields: Vec::new(),
+            copy_instance_on_item_copy: None,
+        }
+    }
+
+    pub fn scope(mut self, scope: &str) -> Self {
+        self.scope = scope.to_string();
+        self
+    }
+
+    pub fn template_key(mut self, template_key: &str) -> Self {
+        self.template_key = Some(template_key.to_string());
+        self
+    }
+
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+
+    pub fn field(mut self, field: NewMetadataField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    pub fn copy_instance_on_item_copy(mut self, copy: bool) -> Self {
+        self.copy_instance_on_item_copy = Some(copy);
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct NewMetadataField {
+    #[serde(rename = "type")]
+    field_type: MetadataFieldType,
+    key: String,
+    display_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hidden: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<NewMetadataOption>,
+}
+
+impl NewMetadataField {
+    pub fn new(field_type: MetadataFieldType, key: &str, display_name: &str) -> NewMetadataField {
+        NewMetadataField {
+            field_type,
+            key: key.to_string(),
+            display_name: display_name.to_string(),
+            description: None,
+            hidden: None,
+            options: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = Some(hidden);
+        self
+    }
+
+    /// Adds a value that an enum or multi-select field can take.
+    pub fn option(mut self, key: &str) -> Self {
+        self.options.push(NewMetadataOption::new(key));
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct NewMetadataOption {
+    key: String,
+}
+
+impl NewMetadataOption {
+    pub fn new(key: &str) -> NewMetadataOption {
+        NewMetadataOption {
+            key: key.to_string(),
+        }
+    }
+}
+
+/// One of the changes that can be made to a template's fields.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "op", rename_all = "camelCase")]
+pub enum MetadataTemplateChange {
+    AddField {
+        data: NewMetadataField,
+    },
+    RemoveField {
+        #[serde(rename = "fieldKey")]
+        field_key: String,
+    },
+    ReorderFields {
+        #[serde(rename = "fieldKeys")]
+        field_keys: Vec<String>,
+    },
+    AddEnumOption {
+        #[serde(rename = "fieldKey")]
+        field_key: String,
+        data: NewMetadataOption,
+    },
+    EditEnumOption {
+        #[serde(rename = "fieldKey")]
+        field_key: String,
+        #[serde(rename = "enumOptionKey")]
+        enum_option_key: String,
+        data: NewMetadataOption,
+    },
+    RemoveEnumOption {
+        #[serde(rename = "fieldKey")]
+        field_key: String,
+        #[serde(rename = "enumOptionKey")]
+        enum_option_key: String,
+    },
+    ReorderEnumOptions {
+        #[serde(rename = "fieldKey")]
+        field_key: String,
+        #[serde(rename = "enumOptionKeys")]
+        enum_option_keys: Vec<String>,
+    },
+}
+
+#[derive(Serialize, Default)]
+#[serde(transparent)]
+pub struct MetadataTemplateUpdates {
+    changes: Vec<MetadataTemplateChange>,
+}
+
+impl MetadataTemplateUpdates {
+    pub fn new() -> MetadataTemplateUpdates {
+        Default::default()
+    }
+
+    pub fn add_field(self, field: NewMetadataField) -> Self {
+        self.change(MetadataTemplateChange::AddField { data: field })
+    }
+
+    pub fn remove_field(self, field_key: &str) -> Self {
+        self.change(MetadataTemplateChange::RemoveField {
+            field_key: field_key.to_string(),
+        })
+    }
+
+    /// Puts the fields in the order of `field_keys`, which must list all of
+    /// the template's fields.
+    pub fn reorder_fields(self, field_keys: &[&str]) -> Self {
+        self.change(MetadataTemplateChange::ReorderFields {
+            field_keys: field_keys.iter().map(|key| key.to_string()).collect(),
+        })
+    }
+
+    pub fn add_enum_option(self, field_key: &str, option_key: &str) -> Self {
+        self.change(MetadataTemplateChange::AddEnumOption {
+            field_key: field_key.to_string(),
+            data: NewMetadataOption::new(option_key),
+        })
+    }
+
+    /// Renames an option of an enum field. Instances that use the option are
+    /// updated to the new name.
+    pub fn edit_enum_option(self, field_key: &str, option_key: &str, new_key: &str) -> Self {
+        self.change(MetadataTemplateChange::EditEnumOption {
+            field_key: field_key.to_string(),
+            enum_option_key: option_key.to_string(),
+            data: NewMetadataOption::new(new_key),
+        })
+    }
+
+    pub fn remove_enum_option(self, field_key: &str, option_key: &str) -> Self {
+        self.change(MetadataTemplateChange::RemoveEnumOption {
+            field_key: field_key.to_string(),
+            enum_option_key: option_key.to_string(),
+        })
+    }
+
+    pub fn reorder_enum_options(self, field_key: &str, option_keys: &[&str]) -> Self {
+        self.change(MetadataTemplateChange::ReorderEnumOptions {
+            field_key: field_key.to_string(),
+            enum_option_keys: option_keys.iter().map(|key| key.to_string()).collect(),
+        })
+    }
+
+    pub fn change(mut self, change: MetadataTemplateChange) -> Self {
+        self.changes.push(change);
+        self
+    }
+}
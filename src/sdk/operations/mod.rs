@@ -1,7 +1,104 @@
+pub(crate) mod comment;
 mod file;
+mod file_version;
 mod folder;
+mod metadata;
+mod metadata_query;
+mod metadata_template;
+mod task;
+mod upload_session;
 mod user;
+mod web_link;
+mod zip_download;
 
+pub use comment::*;
 pub use file::*;
+pub use file_version::*;
 pub use folder::*;
+pub use metadata::*;
+pub use metadata_query::*;
+pub use metadata_template::*;
+pub use task::*;
+pub use upload_session::*;
 pub use user::*;
+pub use web_link::*;
+pub use zip_download::*;
+
+use crate::sdk::models::ClientError;
+use crate::sdk::{Client, Response, SDKError};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+#[derive(Serialize)]
+struct ParentReference {
+    id: String,
+}
+
+impl ParentReference {
+    fn new(folder_id: &str) -> ParentReference {
+        ParentReference {
+            id: folder_id.to_string(),
+        }
+    }
+}
+
+/// Turns a 409 response into a `NameConflict` error, so the items that were in
+/// the way can be read from it.
+async fn check_name_conflict(result: Result<Response, SDKError>) -> Result<Response, SDKError> {
+    match result {
+        Err(SDKError::APIError { response }) if response.status() == http::StatusCode::CONFLICT => {
+            let error: ClientError = response.deserialize().await?;
+            Err(SDKError::NameConflict(error))
+        }
+        result => result,
+    }
+}
+
+/// How many numbered names a copy with `rename` tries before giving up.
+const MAX_COPY_RENAMES: u32 = 100;
+
+/// Posts `body` to the copy endpoint at `url`. With `rename` set, a copy that
+/// clashes with an existing item is retried as `name (1)`, `name (2)` and so on
+/// until a free name is found, up to `MAX_COPY_RENAMES` times, after which the
+/// last conflict is returned.
+async fn copy_item<T: DeserializeOwned>(
+    client: &mut Client,
+    url: &str,
+    mut body: Value,
+    rename: bool,
+) -> Result<T, SDKError> {
+    let mut base_name = None;
+    let mut attempt = 0;
+    loop {
+        let error = match check_name_conflict(client.post(url, &body).await).await {
+            Err(SDKError::NameConflict(error)) if rename => error,
+            result => return result?.deserialize().await,
+        };
+        if base_name.is_none() {
+            base_name = error
+                .conflicts()
+                .first()
+                .and_then(|item| item.name())
+                .map(str::to_owned);
+        }
+
+        attempt += 1;
+        match &base_name {
+            Some(name) if attempt <= MAX_COPY_RENAMES => {
+                body["name"] = json!(numbered_name(name, attempt))
+            }
+            _ => return Err(SDKError::NameConflict(error)),
+        }
+    }
+}
+
+/// Appends ` (n)` to `name`, before its extension if it has one. Used both for
+/// copies in Box and for downloads next to an existing file, so the two are
+/// named alike.
+pub(crate) fn numbered_name(name: &str, n: u32) -> String {
+    match name.rfind('.') {
+        Some(dot) if dot > 0 => format!("{} ({}){}", &name[..dot], n, &name[dot..]),
+        _ => format!("{} ({})", name, n),
+    }
+}
@@ -0,0 +1,145 @@
+use crate::sdk::models::{Collection, ResolutionState, Task, TaskAction, TaskAssignment};
+use crate::sdk::{Client, SDKError};
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use serde_json::json;
+
+pub struct TaskOperation<'a> {
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> TaskOperation<'a> {
+    pub fn new(id: &'a str, client: &'a mut Client) -> TaskOperation<'a> {
+        TaskOperation { id, client }
+    }
+
+    pub async fn get(&mut self) -> Result<Task, SDKError> {
+        let url = format!("/tasks/{}", self.id);
+        let response = self.client.get(&url).await?;
+
+        let task: Task = response.deserialize().await?;
+
+        Ok(task)
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!("/tasks/{}", self.id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+
+    pub async fn assignments(&mut self) -> Result<Collection<TaskAssignment>, SDKError> {
+        let url = format!("/tasks/{}/assignments", self.id);
+        let response = self.client.get(&url).await?;
+
+        let assignments: Collection<TaskAssignment> = response.deserialize().await?;
+
+        Ok(assignments)
+    }
+
+    pub async fn assign_to_user(&mut self, user_id: &str) -> Result<TaskAssignment, SDKError> {
+        self.assign(json!({ "id": user_id })).await
+    }
+
+    /// Assigns the task to the user with the email address `login`.
+    pub async fn assign_to_login(&mut self, login: &str) -> Result<TaskAssignment, SDKError> {
+        self.assign(json!({ "login": login })).await
+    }
+
+    async fn assign(&mut self, assign_to: serde_json::Value) -> Result<TaskAssignment, SDKError> {
+        let body = json!({
+            "task": { "type": "task", "id": self.id },
+            "assign_to": assign_to
+        });
+
+        let response = self.client.post("/task_assignments", body).await?;
+        let assignment: TaskAssignment = response.deserialize().await?;
+        Ok(assignment)
+    }
+}
+
+pub struct TaskAssignmentOperation<'a> {
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> TaskAssignmentOperation<'a> {
+    pub fn new(id: &'a str, client: &'a mut Client) -> TaskAssignmentOperation<'a> {
+        TaskAssignmentOperation { id, client }
+    }
+
+    pub async fn get(&mut self) -> Result<TaskAssignment, SDKError> {
+        let url = format!("/task_assignments/{}", self.id);
+        let response = self.client.get(&url).await?;
+
+        let assignment: TaskAssignment = response.deserialize().await?;
+
+        Ok(assignment)
+    }
+
+    /// Resolves the assignment. Review tasks are `Approved` or `Rejected`,
+    /// and complete tasks are `Completed`.
+    pub async fn resolve(
+        &mut self,
+        state: ResolutionState,
+        message: Option<&str>,
+    ) -> Result<TaskAssignment, SDKError> {
+        let url = format!("/task_assignments/{}", self.id);
+        let mut body = json!({ "resolution_state": state });
+        if let Some(message) = message {
+            body["message"] = json!(message);
+        }
+
+        let response = self.client.put(&url, body).await?;
+        let assignment: TaskAssignment = response.deserialize().await?;
+        Ok(assignment)
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!("/task_assignments/{}", self.id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct TaskItem {
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    id: String,
+}
+
+#[derive(Serialize)]
+pub struct NewTask {
+    item: TaskItem,
+    action: TaskAction,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_at: Option<DateTime<FixedOffset>>,
+}
+
+impl NewTask {
+    pub fn new(file_id: &str, action: TaskAction) -> NewTask {
+        NewTask {
+            item: TaskItem {
+                item_type: "file",
+                id: file_id.to_string(),
+            },
+            action,
+            message: None,
+            due_at: None,
+        }
+    }
+
+    pub fn message(mut self, message: &str) -> Self {
+        self.message = Some(message.to_string());
+        self
+    }
+
+    pub fn due_at(mut self, due_at: DateTime<FixedOffset>) -> Self {
+        self.due_at = Some(due_at);
+        self
+    }
+}
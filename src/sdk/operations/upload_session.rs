@@ -0,0 +1,199 @@
+use crate::sdk::models::{Collection, File, UploadPart, UploadSession};
+use crate::sdk::network::upload_url;
+use crate::sdk::{Body, Client, HTTPMethod, SDKError, UploadState};
+use bytes::Bytes;
+use futures::future::{self, Either};
+use futures::pin_mut;
+use futures::stream::{self, TryStreamExt};
+use serde::Deserialize;
+use serde_json::json;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::Duration;
+use tokio::fs;
+use tokio::io::AsyncReadExt;
+
+/// How many times a commit is retried while Box is still processing the parts.
+const MAX_COMMIT_ATTEMPTS: u32 = 60;
+
+pub struct UploadSessionOperation<'a> {
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+#[derive(Deserialize)]
+struct UploadedPart {
+    part: UploadPart,
+}
+
+impl<'a> UploadSessionOperation<'a> {
+    pub fn new(id: &'a str, client: &'a mut Client) -> UploadSessionOperation<'a> {
+        UploadSessionOperation { id, client }
+    }
+
+    pub async fn get(&mut self) -> Result<UploadSession, SDKError> {
+        let url = upload_url(&format!("/files/upload_sessions/{}", self.id));
+        let response = self.client.get(&url).await?;
+
+        let session: UploadSession = response.deserialize().await?;
+
+        Ok(session)
+    }
+
+    pub async fn list_parts(&mut self) -> Result<Vec<UploadPart>, SDKError> {
+        let mut parts = Vec::new();
+        loop {
+            let url = upload_url(&format!(
+                "/files/upload_sessions/{}/parts?offset={}&limit=1000",
+                self.id,
+                parts.len()
+            ));
+            let response = self.client.get(&url).await?;
+            let page: Collection<UploadPart> = response.deserialize().await?;
+
+            let last_page = page.entries.is_empty();
+            parts.extend(page.entries);
+            if last_page || parts.len() as u64 >= page.total_count {
+                return Ok(parts);
+            }
+        }
+    }
+
+    /// Reads the file at `path` in chunks and uploads up to `concurrency` parts at
+    /// a time, skipping the parts already recorded in `state` and saving `state`
+    /// as each new part finishes. Returns the SHA1 digest of the whole file.
+    pub async fn upload_parts(
+        &mut self,
+        path: &Path,
+        state: &mut UploadState,
+        concurrency: usize,
+    ) -> Result<Vec<u8>, SDKError> {
+        let url = upload_url(&format!("/files/upload_sessions/{}", self.id));
+        let token = self.client.access_token().await?;
+        let client: &Client = self.client;
+        let file = fs::File::open(path).await?;
+        let file_size = state.file_size();
+        let part_size = state.part_size();
+        let uploaded: HashMap<u64, String> = state
+            .parts()
+            .iter()
+            .map(|part| (part.offset(), part.sha1().to_owned()))
+            .collect();
+        let mut hasher = Sha1::new();
+
+        {
+            let uploads = stream::try_unfold((file, 0), |(mut file, offset)| async move {
+                if offset >= file_size {
+                    return Ok(None);
+                }
+                let size = part_size.min(file_size - offset);
+                let mut buffer = vec![0; size as usize];
+                file.read_exact(&mut buffer).await?;
+                Ok::<_, SDKError>(Some(((offset, Bytes::from(buffer)), (file, offset + size))))
+            })
+            .map_ok(|(offset, bytes)| {
+                hasher.update(&bytes);
+                let sha1 = Sha1::digest(&bytes);
+
+                if let Some(uploaded_sha1) = uploaded.get(&offset) {
+                    let result = if *uploaded_sha1 == format!("{:x}", sha1) {
+                        Ok(None)
+                    } else {
+                        Err(SDKError::FileChanged(path.to_owned()))
+                    };
+                    return Either::Left(future::ready(result));
+                }
+
+                let request = client
+                    .network()
+                    .start_request(HTTPMethod::PUT, &url)
+                    .with_header("Authorization", &format!("Bearer {}", token))
+                    .with_header("Content-Type", "application/octet-stream")
+                    .with_header(
+                        "Content-Range",
+                        &format!(
+                            "bytes {}-{}/{}",
+                            offset,
+                            offset + bytes.len() as u64 - 1,
+                            file_size
+                        ),
+                    )
+                    .with_header("Digest", &format!("sha={}", base64::encode(sha1)))
+                    .with_body(Body::Bytes(bytes));
+                Either::Right(async move {
+                    let response = client.network().send_request(request).await?;
+                    let uploaded: UploadedPart = response.deserialize().await?;
+                    Ok(Some(uploaded.part))
+                })
+            })
+            .try_buffer_unordered(concurrency);
+            pin_mut!(uploads);
+
+            while let Some(part) = uploads.try_next().await? {
+                if let Some(part) = part {
+                    state.add_part(part).await?;
+                }
+            }
+        }
+
+        Ok(hasher.finalize().to_vec())
+    }
+
+    /// Commits the session, waiting for Box to finish processing the parts if it
+    /// asks the client to retry, up to `MAX_COMMIT_ATTEMPTS` times. When
+    /// `if_match` is set, the commit fails if the file being replaced no longer
+    /// has that etag.
+    pub async fn commit(
+        &mut self,
+        parts: &[UploadPart],
+        sha1: &[u8],
+        if_match: Option<&str>,
+    ) -> Result<File, SDKError> {
+        let url = upload_url(&format!("/files/upload_sessions/{}/commit", self.id));
+        let digest = format!("sha={}", base64::encode(sha1));
+
+        for _ in 0..MAX_COMMIT_ATTEMPTS {
+            let mut request = self
+                .client
+                .network()
+                .start_request(HTTPMethod::POST, &url)
+                .with_header("Digest", &digest)
+                .with_header("Content-Type", "application/json")
+                .with_body(Body::JSON(json!({ "parts": parts })));
+            if let Some(etag) = if_match {
+                request = request.with_header("If-Match", etag);
+            }
+            let response = self.client.send(request).await?;
+
+            if response.status() == http::StatusCode::ACCEPTED {
+                let retry_after = response
+                    .headers()
+                    .get("Retry-After")
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.parse().ok())
+                    .unwrap_or(1);
+                tokio::time::delay_for(Duration::from_secs(retry_after)).await;
+                continue;
+            }
+
+            let files: Collection<File> = response.deserialize().await?;
+            return files
+                .entries
+                .into_iter()
+                .next()
+                .ok_or_else(|| SDKError::NothingCommitted(self.id.to_owned()));
+        }
+
+        Err(SDKError::CommitTimedOut(
+            self.id.to_owned(),
+            MAX_COMMIT_ATTEMPTS,
+        ))
+    }
+
+    pub async fn abort(&mut self) -> Result<(), SDKError> {
+        let url = upload_url(&format!("/files/upload_sessions/{}", self.id));
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
-use crate::sdk::models::User;
+use crate::sdk::models::{Collection, User};
 use crate::sdk::{Client, SDKError};
+use url::form_urlencoded;
 
 pub struct UserOperation<'a> {
     id: &'a str,
@@ -20,3 +21,95 @@ impl<'a> UserOperation<'a> {
         Ok(user)
     }
 }
+
+pub struct UsersOperation<'a> {
+    client: &'a mut Client,
+}
+
+impl<'a> UsersOperation<'a> {
+    pub fn new(client: &'a mut Client) -> UsersOperation<'a> {
+        UsersOperation { client }
+    }
+
+    pub async fn list(&mut self, query: UserQuery) -> Result<Collection<User>, SDKError> {
+        let url = format!("/users?{}", query.to_query_string());
+        let response = self.client.get(&url).await?;
+
+        let users: Collection<User> = response.deserialize().await?;
+
+        Ok(users)
+    }
+}
+
+pub enum UserType {
+    All,
+    Managed,
+    External,
+}
+
+impl UserType {
+    fn as_str(&self) -> &'static str {
+        match self {
+            UserType::All => "all",
+            UserType::Managed => "managed",
+            UserType::External => "external",
+        }
+    }
+}
+
+pub struct UserQuery {
+    filter_term: Option<String>,
+    user_type: Option<UserType>,
+    limit: u32,
+    offset: u64,
+}
+
+impl Default for UserQuery {
+    fn default() -> UserQuery {
+        UserQuery::new()
+    }
+}
+
+impl UserQuery {
+    pub fn new() -> UserQuery {
+        UserQuery {
+            filter_term: None,
+            user_type: None,
+            limit: 100,
+            offset: 0,
+        }
+    }
+
+    pub fn filter_term(mut self, filter_term: &str) -> Self {
+        self.filter_term = Some(filter_term.to_string());
+        self
+    }
+
+    pub fn user_type(mut self, user_type: UserType) -> Self {
+        self.user_type = Some(user_type);
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn offset(mut self, offset: u64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    fn to_query_string(&self) -> String {
+        let mut query = form_urlencoded::Serializer::new(String::new());
+        if let Some(filter_term) = &self.filter_term {
+            query.append_pair("filter_term", filter_term);
+        }
+        if let Some(user_type) = &self.user_type {
+            query.append_pair("user_type", user_type.as_str());
+        }
+        query.append_pair("limit", &self.limit.to_string());
+        query.append_pair("offset", &self.offset.to_string());
+        query.finish()
+    }
+}
@@ -0,0 +1,117 @@
+use crate::sdk::models::WebLink;
+use crate::sdk::{Client, SDKError};
+use serde::Serialize;
+
+use super::ParentReference;
+
+pub struct WebLinkOperation<'a> {
+    id: &'a str,
+    client: &'a mut Client,
+}
+
+impl<'a> WebLinkOperation<'a> {
+    pub fn new(id: &'a str, client: &'a mut Client) -> WebLinkOperation<'a> {
+        WebLinkOperation { id, client }
+    }
+
+    pub async fn get(&mut self) -> Result<WebLink, SDKError> {
+        let url = format!("/web_links/{}", self.id);
+        let response = self.client.get(&url).await?;
+
+        let web_link: WebLink = response.deserialize().await?;
+
+        Ok(web_link)
+    }
+
+    pub async fn delete(&mut self) -> Result<(), SDKError> {
+        let url = format!("/web_links/{}", self.id);
+        self.client.delete(&url).await?;
+        Ok(())
+    }
+
+    pub async fn update(&mut self, updates: WebLinkUpdates) -> Result<WebLink, SDKError> {
+        let url = format!("/web_links/{}", self.id);
+
+        let response = self.client.put(&url, updates).await?;
+        let web_link: WebLink = response.deserialize().await?;
+        Ok(web_link)
+    }
+
+    pub async fn move_to(&mut self, folder_id: &str) -> Result<WebLink, SDKError> {
+        self.update(WebLinkUpdates::new().parent(folder_id)).await
+    }
+}
+
+#[derive(Serialize)]
+pub struct NewWebLink {
+    url: String,
+    parent: ParentReference,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+}
+
+impl NewWebLink {
+    pub fn new(url: &str, folder_id: &str) -> NewWebLink {
+        NewWebLink {
+            url: url.to_string(),
+            parent: ParentReference::new(folder_id),
+            name: None,
+            description: None,
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+}
+
+#[derive(Serialize, Default)]
+pub struct WebLinkUpdates {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent: Option<ParentReference>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+}
+
+impl WebLinkUpdates {
+    pub fn new() -> WebLinkUpdates {
+        WebLinkUpdates {
+            description: None,
+            name: None,
+            parent: None,
+            url: None,
+        }
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    pub fn parent(mut self, folder_id: &str) -> Self {
+        self.parent = Some(ParentReference::new(folder_id));
+        self
+    }
+
+    pub fn url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_string());
+        self
+    }
+}
@@ -0,0 +1,135 @@
+use super::file::part_path;
+use crate::sdk::models::{ZipDownload, ZipDownloadState, ZipDownloadStatus};
+use crate::sdk::{Client, SDKError};
+use serde::Serialize;
+use std::path::Path;
+use std::time::Duration;
+use tokio::fs;
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+/// How long to wait between checks on an archive Box is still building, and
+/// how many checks to make before giving up on it.
+const ZIP_STATUS_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const MAX_ZIP_STATUS_POLLS: u32 = 300;
+
+pub struct ZipDownloadOperation<'a> {
+    zip: &'a ZipDownload,
+    client: &'a mut Client,
+}
+
+impl<'a> ZipDownloadOperation<'a> {
+    pub fn new(zip: &'a ZipDownload, client: &'a mut Client) -> ZipDownloadOperation<'a> {
+        ZipDownloadOperation { zip, client }
+    }
+
+    /// Downloads the archive to `path`, writing it to a `.part` file next to
+    /// `path` first. Anything already at `path` is only replaced once Box
+    /// reports the archive is complete, and the `.part` file is removed if it
+    /// isn't.
+    pub async fn download(&mut self, path: &Path) -> Result<ZipDownloadStatus, SDKError> {
+        let part_path = part_path(path, None);
+        let mut file = fs::File::create(&part_path).await?;
+        let result = self.download_to(&mut file).await;
+        drop(file);
+
+        match result {
+            Ok(status) => {
+                fs::rename(&part_path, path).await?;
+                Ok(status)
+            }
+            Err(err) => {
+                // An archive can't be resumed, so there's nothing to keep
+                let _ = fs::remove_file(&part_path).await;
+                Err(err)
+            }
+        }
+    }
+
+    /// Streams the archive to `writer`, then waits for Box to report how many
+    /// items made it into the archive. Fails with `SDKError::ZipDownloadFailed`
+    /// if Box couldn't finish the archive.
+    pub async fn download_to<W: AsyncWrite + Unpin>(
+        &mut self,
+        writer: &mut W,
+    ) -> Result<ZipDownloadStatus, SDKError> {
+        let mut response = self.client.get(self.zip.download_url()).await?;
+        while let Some(bytes) = response.chunk().await? {
+            writer.write_all(&bytes).await?;
+        }
+        writer.flush().await?;
+
+        let status = self.wait().await?;
+        match status.state() {
+            ZipDownloadState::Failed => Err(SDKError::ZipDownloadFailed {
+                downloaded: status.downloaded_file_count(),
+                total: status.total_file_count(),
+            }),
+            _ => Ok(status),
+        }
+    }
+
+    pub async fn status(&mut self) -> Result<ZipDownloadStatus, SDKError> {
+        let response = self.client.get(self.zip.status_url()).await?;
+        let status: ZipDownloadStatus = response.deserialize().await?;
+        Ok(status)
+    }
+
+    /// Polls the status until the download has either succeeded or failed.
+    /// Fails with `SDKError::ZipDownloadTimedOut` if it's still in progress
+    /// after `MAX_ZIP_STATUS_POLLS` checks.
+    pub async fn wait(&mut self) -> Result<ZipDownloadStatus, SDKError> {
+        for _ in 0..MAX_ZIP_STATUS_POLLS {
+            let status = self.status().await?;
+            if *status.state() != ZipDownloadState::InProgress {
+                return Ok(status);
+            }
+            tokio::time::delay_for(ZIP_STATUS_POLL_INTERVAL).await;
+        }
+
+        Err(SDKError::ZipDownloadTimedOut(
+            ZIP_STATUS_POLL_INTERVAL * MAX_ZIP_STATUS_POLLS,
+        ))
+    }
+}
+
+#[derive(Serialize)]
+struct ZipItem {
+    #[serde(rename = "type")]
+    item_type: &'static str,
+    id: String,
+}
+
+#[derive(Serialize, Default)]
+pub struct NewZipDownload {
+    items: Vec<ZipItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    download_file_name: Option<String>,
+}
+
+impl NewZipDownload {
+    pub fn new() -> NewZipDownload {
+        Default::default()
+    }
+
+    pub fn file(mut self, file_id: &str) -> Self {
+        self.items.push(ZipItem {
+            item_type: "file",
+            id: file_id.to_string(),
+        });
+        self
+    }
+
+    pub fn folder(mut self, folder_id: &str) -> Self {
+        self.items.push(ZipItem {
+            item_type: "folder",
+            id: folder_id.to_string(),
+        });
+        self
+    }
+
+    /// Names the archive, without the `.zip` extension.
+    pub fn name(mut self, name: &str) -> Self {
+        self.download_file_name = Some(name.to_string());
+        self
+    }
+}
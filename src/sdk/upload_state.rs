@@ -0,0 +1,111 @@
+use super::models::{UploadPart, UploadSession};
+use super::SDKError;
+use serde::{Deserialize, Serialize};
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::fs;
+
+/// Progress of a chunked upload, saved next to the file being uploaded so the
+/// upload can be resumed after the process exits.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct UploadState {
+    #[serde(skip)]
+    path: PathBuf,
+    session_id: String,
+    part_size: u64,
+    file_size: u64,
+    modified: Option<SystemTime>,
+    #[serde(default)]
+    if_match: Option<String>,
+    parts: Vec<UploadPart>,
+}
+
+impl UploadState {
+    pub fn new(path: &Path, session: &UploadSession, metadata: &Metadata) -> UploadState {
+        UploadState {
+            path: UploadState::state_path(path),
+            session_id: session.id().to_owned(),
+            part_size: session.part_size(),
+            file_size: metadata.len(),
+            modified: metadata.modified().ok(),
+            if_match: None,
+            parts: Vec::new(),
+        }
+    }
+
+    /// Only commit the upload if the file's etag still matches `etag`.
+    pub fn with_if_match(mut self, etag: &str) -> UploadState {
+        self.if_match = Some(etag.to_owned());
+        self
+    }
+
+    /// Loads the saved state for an upload of the file at `path`.
+    pub async fn load(path: &Path) -> Result<UploadState, SDKError> {
+        let state_path = UploadState::state_path(path);
+        let contents = match fs::read(&state_path).await {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(SDKError::NoUploadToResume(path.to_owned()))
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        let mut state: UploadState = serde_json::from_slice(&contents)?;
+        state.path = state_path;
+        Ok(state)
+    }
+
+    fn state_path(path: &Path) -> PathBuf {
+        let mut state_path = path.as_os_str().to_owned();
+        state_path.push(".bx-upload");
+        PathBuf::from(state_path)
+    }
+
+    pub fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    pub fn part_size(&self) -> u64 {
+        self.part_size
+    }
+
+    pub fn file_size(&self) -> u64 {
+        self.file_size
+    }
+
+    pub fn if_match(&self) -> Option<&str> {
+        self.if_match.as_deref()
+    }
+
+    pub fn parts(&self) -> &[UploadPart] {
+        &self.parts
+    }
+
+    /// Whether the file described by `metadata` looks like the one this upload
+    /// was started with.
+    pub fn matches(&self, metadata: &Metadata) -> bool {
+        metadata.len() == self.file_size && metadata.modified().ok() == self.modified
+    }
+
+    pub fn set_parts(&mut self, mut parts: Vec<UploadPart>) {
+        parts.sort_by_key(|part| part.offset());
+        self.parts = parts;
+    }
+
+    pub async fn add_part(&mut self, part: UploadPart) -> Result<(), SDKError> {
+        self.parts.push(part);
+        self.parts.sort_by_key(|part| part.offset());
+        self.save().await
+    }
+
+    pub async fn save(&self) -> Result<(), SDKError> {
+        fs::write(&self.path, serde_json::to_vec(self)?).await?;
+        Ok(())
+    }
+
+    pub async fn remove(&self) -> Result<(), SDKError> {
+        fs::remove_file(&self.path).await?;
+        Ok(())
+    }
+}
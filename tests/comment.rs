@@ -0,0 +1,100 @@
+use httpmock::Method::{GET, POST};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::{assert_output_json, cmd};
+
+#[test]
+#[with_mock_server]
+fn bx_comment_create_sends_mentions_as_tagged_message() {
+    let mut cmd = cmd();
+    cmd.args([
+        "comment",
+        "create",
+        "@[11:Jane Doe] please review",
+        "--file",
+        "6",
+    ]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let create_mock = mock(POST, "/comments")
+        .expect_json_body(&json!({
+            "tagged_message": "@[11:Jane Doe] please review",
+            "item": { "type": "file", "id": "6" }
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "comment",
+            "id": "100",
+            "is_reply_comment": false,
+            "message": "@Jane Doe please review",
+            "item": { "type": "file", "id": "6" }
+        }))
+        .create();
+
+    let expected = json!({
+        "type": "comment",
+        "id": "100",
+        "is_reply_comment": false,
+        "message": "@Jane Doe please review",
+        "tagged_message": null,
+        "created_by": null,
+        "created_at": null,
+        "modified_at": null,
+        "item": { "type": "file", "id": "6" }
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(create_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_comments_groups_replies_into_threads() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "comments", "-t", "access_token", "--json"]);
+
+    let comment = |id: &str, reply_to: Option<&str>, created_at: &str| {
+        let item = match reply_to {
+            Some(parent) => json!({ "type": "comment", "id": parent }),
+            None => json!({ "type": "file", "id": "6" }),
+        };
+        json!({
+            "type": "comment",
+            "id": id,
+            "is_reply_comment": reply_to.is_some(),
+            "message": format!("Comment {}", id),
+            "created_at": created_at,
+            "item": item
+        })
+    };
+
+    let comments_mock = mock(GET, "/files/6/comments")
+        .expect_query_param("offset", "0")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_count": 3,
+            "entries": [
+                comment("102", None, "2020-05-01T12:00:00-07:00"),
+                comment("103", Some("101"), "2020-05-01T13:00:00-07:00"),
+                comment("101", None, "2020-05-01T11:00:00-07:00")
+            ]
+        }))
+        .create();
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let threads: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let ids: Vec<(&str, Vec<&str>)> = threads
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|thread| {
+            let replies = thread["replies"].as_array().unwrap();
+            let reply_ids = replies.iter().map(|r| r["id"].as_str().unwrap()).collect();
+            (thread["comment"]["id"].as_str().unwrap(), reply_ids)
+        })
+        .collect();
+    assert_eq!(ids, vec![("101", vec!["103"]), ("102", vec![])]);
+    assert_eq!(comments_mock.times_called(), 1);
+}
@@ -1,6 +1,7 @@
 use assert_cmd::Command;
 use assert_json_diff::assert_json_eq;
 
+#[allow(dead_code)]
 pub fn assert_output_json(mut cmd: Command, expected: serde_json::Value) {
     let result = cmd.output().unwrap();
     println!("{:?}", result);
@@ -13,5 +14,44 @@ pub fn assert_output_json(mut cmd: Command, expected: serde_json::Value) {
 pub fn cmd() -> Command {
     let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
     cmd.env("BOX_API_ROOT", "http://localhost:5000");
+    cmd.env("BOX_UPLOAD_ROOT", "http://localhost:5000");
     cmd
 }
+
+/// The `--json` output for a file, with every field not in `fields` set to null.
+#[allow(dead_code)]
+pub fn file_json(fields: serde_json::Value) -> serde_json::Value {
+    let mut file = serde_json::json!({
+        "type": "file",
+        "etag": null,
+        "name": null,
+        "sequence_id": null,
+        "allowed_invitee_roles": null,
+        "collections": null,
+        "content_created_at": null,
+        "content_modified_at": null,
+        "created_at": null,
+        "created_by": null,
+        "description": null,
+        "file_version": null,
+        "has_collaborations": null,
+        "is_externally_owned": null,
+        "item_status": null,
+        "lock": null,
+        "modified_at": null,
+        "modified_by": null,
+        "owned_by": null,
+        "parent": null,
+        "path_collection": null,
+        "purged_at": null,
+        "sha1": null,
+        "shared_link": null,
+        "size": null,
+        "tags": null,
+        "trashed_at": null
+    });
+    for (key, value) in fields.as_object().unwrap() {
+        file[key] = value.clone();
+    }
+    file
+}
@@ -0,0 +1,1084 @@
+use httpmock::Method::{GET, OPTIONS, POST, PUT};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+use std::path::PathBuf;
+
+mod common;
+use common::{assert_output_json, cmd, file_json};
+
+fn write_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_preserves_unknown_values_and_fields() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "-t", "access_token", "--json"]);
+
+    let json = json!({
+        "type": "file",
+        "id": "6",
+        "etag": "2",
+        "name": "report.pdf",
+        "sequence_id": "2",
+        "created_at": "2020-05-01T10:00:00-07:00",
+        "item_status": "archived",
+        "extension": "pdf",
+        "shared_link": {
+            "access": "open",
+            "download_count": 3,
+            "effective_access": "open",
+            "effective_permission": "can_edit",
+            "is_password_enabled": false,
+            "permissions": {
+                "can_download": true,
+                "can_preview": true,
+                "can_edit": true
+            },
+            "preview_count": 5,
+            "unshared_at": null,
+            "url": "https://app.box.com/s/abc",
+            "vanity_url": null,
+            "vanity_name": null
+        }
+    });
+
+    let file_mock = mock(GET, "/files/6")
+        .expect_header("authorization", "Bearer access_token")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    let expected = json!({
+        "type": "file",
+        "id": "6",
+        "etag": "2",
+        "name": "report.pdf",
+        "sequence_id": "2",
+        "allowed_invitee_roles": null,
+        "collections": null,
+        "content_created_at": null,
+        "content_modified_at": null,
+        "created_at": "2020-05-01T10:00:00-07:00",
+        "created_by": null,
+        "description": null,
+        "file_version": null,
+        "has_collaborations": null,
+        "is_externally_owned": null,
+        "item_status": "archived",
+        "lock": null,
+        "modified_at": null,
+        "modified_by": null,
+        "owned_by": null,
+        "parent": null,
+        "path_collection": null,
+        "purged_at": null,
+        "sha1": null,
+        "shared_link": {
+            "access": "open",
+            "download_count": 3,
+            "effective_access": "open",
+            "effective_permission": "can_edit",
+            "is_password_enabled": false,
+            "permissions": {
+                "can_download": true,
+                "can_preview": true,
+                "can_edit": true
+            },
+            "preview_count": 5,
+            "unshared_at": null,
+            "url": "https://app.box.com/s/abc",
+            "vanity_url": null,
+            "vanity_name": null
+        },
+        "size": null,
+        "tags": null,
+        "trashed_at": null,
+        "extension": "pdf"
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(file_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_upload_version_uploads_new_content() {
+    let path = write_file("bx-file-version.txt", "Version 2\n");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "upload-version", path.to_str().unwrap()]);
+    cmd.args(["--if-match", "2", "--name", "report-v2.txt"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let upload_mock = mock(POST, "/files/6/content")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_header("if-match", "2")
+        .expect_header("content-md5", "99475d333f002a48c5945713f8003a5dce38d256")
+        .expect_body_contains("{\"name\":\"report-v2.txt\"}")
+        .expect_body_contains("Version 2")
+        .return_status(201)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{ "type": "file", "id": "6", "etag": "3", "name": "report-v2.txt" }]
+        }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6", "etag": "3", "name": "report-v2.txt" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(upload_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_upload_version_uses_upload_session_above_threshold() {
+    let path = write_file("bx-file-version-chunked.txt", "Version 2\n");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "upload-version", path.to_str().unwrap()]);
+    cmd.args(["--if-match", "2", "--chunked-threshold", "4"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let preflight_mock = mock(OPTIONS, "/files/6/content")
+        .expect_json_body(&json!({ "size": 10 }))
+        .return_status(200)
+        .return_json_body(
+            &json!({ "upload_url": "https://upload.box.com/api/2.0/files/6/content" }),
+        )
+        .create();
+    let session_mock = mock(POST, "/files/6/upload_sessions")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_json_body(&json!({ "file_size": 10 }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "upload_session",
+            "id": "0A1B2C3D",
+            "part_size": 8,
+            "total_parts": 2
+        }))
+        .create();
+
+    let part_mock = mock(PUT, "/files/upload_sessions/0A1B2C3D")
+        .return_status(200)
+        .return_json_body(&json!({
+            "part": { "part_id": "BFDF5379", "offset": 0, "size": 8, "sha1": "8b5fc6e8fb6fb3bb1a06e5bc28a2e8e36ac0e0a8" }
+        }))
+        .create();
+
+    let commit_mock = mock(POST, "/files/upload_sessions/0A1B2C3D/commit")
+        .expect_header("if-match", "2")
+        .return_status(201)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{ "type": "file", "id": "6", "etag": "3" }]
+        }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6", "etag": "3" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(session_mock.times_called(), 1);
+    assert_eq!(part_mock.times_called(), 2);
+    assert_eq!(commit_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_upload_version_stops_when_preflight_is_rejected() {
+    let path = write_file("bx-file-version-rejected.txt", "Version 2\n");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "upload-version", path.to_str().unwrap()]);
+    cmd.args(["--chunked-threshold", "4", "-t", "access_token"]);
+
+    let preflight_mock = mock(OPTIONS, "/files/6/content")
+        .return_status(403)
+        .return_json_body(&json!({
+            "type": "error",
+            "status": 403,
+            "code": "storage_limit_exceeded",
+            "message": "Account storage limit reached"
+        }))
+        .create();
+    let session_mock = mock(POST, "/files/6/upload_sessions")
+        .return_status(201)
+        .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "upload rejected: Account storage limit reached",
+    ));
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(session_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_deletes_corrupt_download() {
+    let path = std::env::temp_dir().join("bx-file-download-corrupt.txt");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "download", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "85136c79cbf9fe36bb9d05d0639c70c265c18d37"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .return_status(200)
+        .return_body("Corrupted content\n")
+        .create();
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("integrity check failed"));
+    assert!(!path.exists());
+    assert_eq!(sha1_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_resumes_from_part_file() {
+    let path = std::env::temp_dir().join("bx-file-download-resume.txt");
+    let _ = std::fs::remove_file(&path);
+    let part_path = write_file("bx-file-download-resume.txt.part", "Hello, ");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "download", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .expect_header("range", "bytes=7-")
+        .return_status(206)
+        .return_body("Box!\n")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello, Box!\n");
+    assert!(!part_path.exists());
+    assert_eq!(sha1_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_range() {
+    let path = std::env::temp_dir().join("bx-file-download-range.txt");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "download",
+        path.to_str().unwrap(),
+        "--range",
+        "0-4",
+    ]);
+    cmd.args(["-t", "access_token"]);
+
+    let content_mock = mock(GET, "/files/6/content")
+        .expect_header("range", "bytes=0-4")
+        .return_status(206)
+        .return_body("Hello")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello");
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_range_ignores_whole_file_part() {
+    let path = std::env::temp_dir().join("bx-file-download-range-part.txt");
+    let _ = std::fs::remove_file(&path);
+    let part_path = write_file("bx-file-download-range-part.txt.part", "Hello, ");
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "download",
+        path.to_str().unwrap(),
+        "--range",
+        "5-9",
+    ]);
+    cmd.args(["-t", "access_token"]);
+
+    let content_mock = mock(GET, "/files/6/content")
+        .expect_header("range", "bytes=5-9")
+        .return_status(206)
+        .return_body(", Box")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), ", Box");
+    assert_eq!(std::fs::read_to_string(&part_path).unwrap(), "Hello, ");
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+    std::fs::remove_file(&part_path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_parallel_fetches_ranges() {
+    let path = std::env::temp_dir().join("bx-file-download-parallel.txt");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "download",
+        path.to_str().unwrap(),
+        "--parallel",
+    ]);
+    cmd.args([
+        "--part-size",
+        "5",
+        "--concurrency",
+        "2",
+        "-t",
+        "access_token",
+    ]);
+
+    let info_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "size,sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "size": 12,
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let range_mocks: Vec<_> = [
+        ("bytes=0-4", "Hello"),
+        ("bytes=5-9", ", Box"),
+        ("bytes=10-11", "!\n"),
+    ]
+    .iter()
+    .map(|(range, body)| {
+        mock(GET, "/files/6/content")
+            .expect_header("range", range)
+            .return_status(206)
+            .return_body(body)
+            .create()
+    })
+    .collect();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello, Box!\n");
+    assert_eq!(info_mock.times_called(), 1);
+    for range_mock in range_mocks {
+        assert_eq!(range_mock.times_called(), 1);
+    }
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_parallel_without_size_uses_one_stream() {
+    let path = std::env::temp_dir().join("bx-file-download-parallel-no-size.txt");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "download",
+        path.to_str().unwrap(),
+        "--parallel",
+    ]);
+    cmd.args(["-t", "access_token"]);
+
+    let info_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "size,sha1")
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6" }))
+        .create();
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .return_status(200)
+        .return_body("Hello, Box!\n")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello, Box!\n");
+    assert_eq!(info_mock.times_called(), 1);
+    assert_eq!(sha1_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_writes_to_stdout() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "download", "-", "-t", "access_token"]);
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .return_status(200)
+        .return_body("Hello, Box!\n")
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout("Hello, Box!\n")
+        .stderr(predicates::str::contains("Downloading file 6"));
+    assert_eq!(sha1_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+}
+
+fn download_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir(&dir).unwrap();
+    std::fs::write(dir.join("report.txt"), "Old content\n").unwrap();
+    dir
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_to_directory_renames_existing() {
+    let dir = download_dir("bx-file-download-rename");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "download", dir.to_str().unwrap(), "--rename"]);
+    cmd.args(["-t", "access_token"]);
+
+    let info_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "name,content_modified_at")
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "name": "report.txt" }))
+        .create();
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .return_status(200)
+        .return_body("Hello, Box!\n")
+        .create();
+
+    cmd.assert().success();
+    let read = |name| std::fs::read_to_string(dir.join(name)).unwrap();
+    assert_eq!(read("report.txt"), "Old content\n");
+    assert_eq!(read("report (1).txt"), "Hello, Box!\n");
+    assert_eq!(info_mock.times_called(), 1);
+    assert_eq!(sha1_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_download_if_newer_keeps_newer_local_file() {
+    let dir = download_dir("bx-file-download-if-newer");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "download", dir.to_str().unwrap(), "--if-newer"]);
+    cmd.args(["-t", "access_token"]);
+
+    let info_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "name,content_modified_at")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "name": "report.txt",
+            "content_modified_at": "2020-05-01T10:00:00-07:00"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .return_status(200)
+        .return_body("Hello, Box!\n")
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains(format!(
+            "File 6 skipped, {} already exists",
+            dir.join("report.txt").display()
+        )));
+    let contents = std::fs::read_to_string(dir.join("report.txt")).unwrap();
+    assert_eq!(contents, "Old content\n");
+    assert_eq!(info_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_copy_renames_on_conflict() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "copy", "--to", "3", "--rename"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let conflict_mock = mock(POST, "/files/6/copy")
+        .expect_json_body(&json!({ "parent": { "id": "3" } }))
+        .return_status(409)
+        .return_json_body(&json!({
+            "type": "error",
+            "status": 409,
+            "code": "item_name_in_use",
+            "message": "Item with the same name already exists",
+            "context_info": {
+                "conflicts": { "type": "file", "id": "9", "name": "report.pdf" }
+            }
+        }))
+        .create();
+
+    let copy_mock = mock(POST, "/files/6/copy")
+        .expect_json_body(&json!({ "parent": { "id": "3" }, "name": "report (1).pdf" }))
+        .return_status(201)
+        .return_json_body(&json!({ "type": "file", "id": "10", "name": "report (1).pdf" }))
+        .create();
+
+    let expected = file_json(json!({ "id": "10", "name": "report (1).pdf" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(conflict_mock.times_called(), 1);
+    assert_eq!(copy_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_copy_gives_up_renaming_after_too_many_conflicts() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "copy", "--to", "3", "--rename"]);
+    cmd.args(["-t", "access_token"]);
+
+    let conflict_mock = mock(POST, "/files/6/copy")
+        .return_status(409)
+        .return_json_body(&json!({
+            "type": "error",
+            "status": 409,
+            "code": "item_name_in_use",
+            "message": "Item with the same name already exists",
+            "context_info": {
+                "conflicts": { "type": "file", "id": "9", "name": "report.pdf" }
+            }
+        }))
+        .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "name already in use: Item with the same name already exists",
+    ));
+    assert_eq!(conflict_mock.times_called(), 101);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_move_sets_parent() {
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "move",
+        "--to",
+        "3",
+        "-t",
+        "access_token",
+        "--json",
+    ]);
+
+    let move_mock = mock(PUT, "/files/6")
+        .expect_json_body(&json!({ "parent": { "id": "3" } }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "name": "report.pdf" }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6", "name": "report.pdf" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(move_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_update_edits_tags_and_clears_description() {
+    let mut cmd = cmd();
+    cmd.args([
+        "file",
+        "6",
+        "update",
+        "--add-tag",
+        "new",
+        "--remove-tag",
+        "old",
+    ]);
+    cmd.args(["--clear-description", "-t", "access_token", "--json"]);
+
+    let tags_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "tags")
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "tags": ["old", "keep"] }))
+        .create();
+
+    let update_mock = mock(PUT, "/files/6")
+        .expect_json_body(&json!({ "description": null, "tags": ["keep", "new"] }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "tags": ["keep", "new"] }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6", "tags": ["keep", "new"] }));
+    assert_output_json(cmd, expected);
+    assert_eq!(tags_mock.times_called(), 1);
+    assert_eq!(update_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_update_sends_only_the_changed_fields() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "update", "--clear-disposition-at"]);
+    cmd.args([
+        "--shared-link-access",
+        "open",
+        "--clear-shared-link-password",
+    ]);
+    cmd.args(["--collection", "11", "--remove-collection", "12"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let collections_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "collections")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "collections": [
+                { "type": "collection", "id": "10", "name": "Favorites", "collection_type": "favorites" },
+                { "type": "collection", "id": "12" }
+            ]
+        }))
+        .create();
+
+    // Cleared fields are sent as null, and fields that weren't asked for are
+    // left out entirely
+    let update_mock = mock(PUT, "/files/6")
+        .expect_json_body(&json!({
+            "disposition_at": null,
+            "shared_link": { "access": "open", "password": null },
+            "collections": [{ "id": "10" }, { "id": "11" }]
+        }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6" }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(collections_mock.times_called(), 1);
+    assert_eq!(update_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_update_removes_shared_link() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "update", "--remove-shared-link"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let update_mock = mock(PUT, "/files/6")
+        .expect_json_body(&json!({ "shared_link": null }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6" }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(update_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_lock_sets_lock() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "lock", "--expires", "2h", "--prevent-download"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let lock = json!({
+        "type": "lock",
+        "id": "112",
+        "created_by": { "type": "user", "id": "11", "name": "Jane Doe" },
+        "created_at": "2020-05-01T10:00:00-07:00",
+        "expired_at": "2020-05-01T12:00:00-07:00",
+        "is_download_prevented": true,
+        "app_type": null
+    });
+    let lock_mock = mock(PUT, "/files/6")
+        .expect_query_param("fields", "name,lock")
+        .expect_body_contains("\"access\":\"lock\"")
+        .expect_body_contains("\"expires_at\"")
+        .expect_body_contains("\"is_download_prevented\":true")
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "name": "report.pdf", "lock": lock }))
+        .create();
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let output: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(output["lock"]["created_by"]["name"], "Jane Doe");
+    assert_eq!(output["lock"]["expired_at"], "2020-05-01T12:00:00-07:00");
+    assert_eq!(lock_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_shows_lock_holder() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "-t", "access_token", "--json"]);
+
+    let file_mock = mock(GET, "/files/6")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "name": "report.pdf",
+            "lock": {
+                "type": "lock",
+                "id": "112",
+                "created_by": { "type": "user", "id": "11", "name": "Jane Doe" },
+                "created_at": "2020-05-01T10:00:00-07:00",
+                "expired_at": "2020-05-01T12:00:00-07:00",
+                "is_download_prevented": false,
+                "app_type": null
+            }
+        }))
+        .create();
+
+    let output = cmd.output().unwrap();
+    assert!(output.status.success());
+    let output: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(output["lock"]["created_by"]["id"], "11");
+    assert_eq!(output["lock"]["created_by"]["name"], "Jane Doe");
+    assert_eq!(output["lock"]["expired_at"], "2020-05-01T12:00:00-07:00");
+    assert_eq!(file_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_lock_rejects_out_of_range_expiry() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "lock", "--expires", "9999999999999d"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let lock_mock = mock(PUT, "/files/6").create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "invalid duration: 9999999999999d",
+    ));
+    assert_eq!(lock_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_lock_rejects_expiry_in_the_past() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "lock", "--expires=-2h"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let lock_mock = mock(PUT, "/files/6").create();
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("invalid duration: -2h"));
+    assert_eq!(lock_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_unlock_clears_lock() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "unlock", "-t", "access_token", "--json"]);
+
+    let unlock_mock = mock(PUT, "/files/6")
+        .expect_json_body(&json!({ "lock": null }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6", "name": "report.pdf", "lock": null }))
+        .create();
+
+    let expected = file_json(json!({ "id": "6", "name": "report.pdf" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(unlock_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_rep_get_generates_and_downloads_representation() {
+    let path = std::env::temp_dir().join("bx-file-rep.png");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "rep", "get", "png", "--size", "320x320"]);
+    cmd.args(["-o", path.to_str().unwrap(), "-t", "access_token"]);
+
+    let rep_url = "/internal_files/6/versions/1/representations/png_320x320";
+    let representation = |state: &str| {
+        json!({
+            "representation": "png",
+            "properties": { "dimensions": "320x320", "paged": "false", "thumb": "true" },
+            "info": { "url": format!("http://localhost:5000{}", rep_url) },
+            "status": { "state": state },
+            "content": {
+                "url_template": format!("http://localhost:5000{}/content/{{+asset_path}}", rep_url)
+            }
+        })
+    };
+
+    let file_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "representations")
+        .expect_header("x-rep-hints", "[png?dimensions=320x320]")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "representations": { "entries": [representation("none")] }
+        }))
+        .create();
+
+    let info_mock = mock(GET, rep_url)
+        .return_status(200)
+        .return_json_body(&representation("success"))
+        .create();
+
+    let content_mock = mock(GET, &format!("{}/content/", rep_url))
+        .return_status(200)
+        .return_body("PNG")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "PNG");
+    assert_eq!(file_mock.times_called(), 1);
+    assert_eq!(info_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+fn version_json(fields: serde_json::Value) -> serde_json::Value {
+    let mut version = json!({
+        "id": null,
+        "sha1": null,
+        "name": null,
+        "size": null,
+        "version_number": null,
+        "created_at": null,
+        "modified_at": null,
+        "modified_by": null,
+        "trashed_at": null,
+        "trashed_by": null,
+        "restored_at": null,
+        "restored_by": null,
+        "purged_at": null,
+        "uploader_display_name": null
+    });
+    for (key, value) in fields.as_object().unwrap() {
+        version[key] = value.clone();
+    }
+    version
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_versions_lists_versions() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "versions", "-t", "access_token", "--json"]);
+
+    let versions_mock = mock(GET, "/files/6/versions")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{
+                "type": "file_version",
+                "id": "12345",
+                "sha1": "134b65991ed521fcfe4724b7d814ab8ded5185dc",
+                "version_number": "1"
+            }]
+        }))
+        .create();
+
+    let expected = json!({
+        "total_count": 1,
+        "entries": [version_json(json!({
+            "type": "file_version",
+            "id": "12345",
+            "sha1": "134b65991ed521fcfe4724b7d814ab8ded5185dc",
+            "version_number": "1"
+        }))]
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(versions_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_versions_download_fetches_version_content() {
+    let path = std::env::temp_dir().join("bx-file-version-download.txt");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "versions", "download", "12345"]);
+    cmd.args([path.to_str().unwrap(), "-t", "access_token"]);
+
+    let version_mock = mock(GET, "/files/6/versions/12345")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file_version",
+            "id": "12345",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .expect_query_param("version", "12345")
+        .return_status(200)
+        .return_body("Hello, Box!\n")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello, Box!\n");
+    assert_eq!(version_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_versions_download_resumes_from_part_file() {
+    let path = std::env::temp_dir().join("bx-file-version-resume.txt");
+    let _ = std::fs::remove_file(&path);
+    let part_path = write_file("bx-file-version-resume.txt.12345.part", "Hello, ");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "versions", "download", "12345"]);
+    cmd.args([path.to_str().unwrap(), "-t", "access_token"]);
+
+    let version_mock = mock(GET, "/files/6/versions/12345")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file_version",
+            "id": "12345",
+            "sha1": "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d"
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/files/6/content")
+        .expect_query_param("version", "12345")
+        .expect_header("range", "bytes=7-")
+        .return_status(206)
+        .return_body("Box!\n")
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "Hello, Box!\n");
+    assert!(!part_path.exists());
+    assert_eq!(version_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_versions_promote() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "versions", "promote", "12345"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let promote_mock = mock(POST, "/files/6/versions/current")
+        .expect_json_body(&json!({ "type": "file_version", "id": "12345" }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "file_version",
+            "id": "67890",
+            "sha1": "134b65991ed521fcfe4724b7d814ab8ded5185dc",
+            "version_number": "3"
+        }))
+        .create();
+
+    let expected = version_json(json!({
+        "type": "file_version",
+        "id": "67890",
+        "sha1": "134b65991ed521fcfe4724b7d814ab8ded5185dc",
+        "version_number": "3"
+    }));
+    assert_output_json(cmd, expected);
+    assert_eq!(promote_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_verify_compares_sha1() {
+    let path = write_file("bx-file-verify.txt", "Version 2\n");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "verify", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "file",
+            "id": "6",
+            "sha1": "99475d333f002a48c5945713f8003a5dce38d256"
+        }))
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(format!("File 6 matches {}\n", path.to_str().unwrap()));
+    assert_eq!(sha1_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_verify_reports_missing_sha1() {
+    let path = write_file("bx-file-verify-no-sha1.txt", "Version 2\n");
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "verify", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let sha1_mock = mock(GET, "/files/6")
+        .expect_query_param("fields", "sha1")
+        .return_status(200)
+        .return_json_body(&json!({ "type": "file", "id": "6" }))
+        .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "file 6 has no SHA1 to check against",
+    ));
+    assert_eq!(sha1_mock.times_called(), 1);
+}
@@ -1,4 +1,4 @@
-use httpmock::Method::GET;
+use httpmock::Method::{GET, PUT};
 use httpmock::{mock, with_mock_server};
 use serde_json::json;
 
@@ -26,6 +26,7 @@ fn bx_folder_displays_root_folder() {
         .create();
 
     let expected = json!({
+        "type": "folder",
         "id": "0",
         "etag": null,
         "name": "All Files",
@@ -56,6 +57,7 @@ fn bx_folder_id_displays_folder() {
         .create();
 
     let expected = json!({
+        "type": "folder",
         "id": "3",
         "etag": "1",
         "name": "Test Folder",
@@ -64,3 +66,139 @@ fn bx_folder_id_displays_folder() {
     assert_output_json(cmd, expected);
     assert_eq!(search_mock.times_called(), 1);
 }
+
+#[test]
+#[with_mock_server]
+fn bx_folder_items_lists_web_links() {
+    let mut cmd = cmd();
+    cmd.args(["folder", "3", "items", "-t", "access_token", "--json"]);
+
+    let json = json!({
+        "total_count": 2,
+        "entries": [
+            {
+                "type": "folder",
+                "id": "4",
+                "etag": "1",
+                "name": "Subfolder",
+                "sequence_id": "1"
+            },
+            {
+                "type": "web_link",
+                "id": "5",
+                "etag": "1",
+                "name": "Example",
+                "sequence_id": "1",
+                "url": "https://example.com"
+            }
+        ]
+    });
+
+    let items_mock = mock(GET, "/folders/3/items")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_query_param("limit", "100")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    let expected = json!({
+        "total_count": 2,
+        "entries": [
+            {
+                "type": "folder",
+                "id": "4",
+                "etag": "1",
+                "name": "Subfolder",
+                "sequence_id": "1"
+            },
+            {
+                "type": "web_link",
+                "id": "5",
+                "etag": "1",
+                "name": "Example",
+                "sequence_id": "1",
+                "url": "https://example.com",
+                "description": null,
+                "created_at": null,
+                "created_by": null,
+                "item_status": null,
+                "modified_at": null,
+                "modified_by": null,
+                "owned_by": null,
+                "parent": null,
+                "path_collection": null,
+                "purged_at": null,
+                "shared_link": null,
+                "trashed_at": null
+            }
+        ]
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(items_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_folder_items_preserves_unknown_item_types() {
+    let mut cmd = cmd();
+    cmd.args(["folder", "3", "items", "-t", "access_token", "--json"]);
+
+    let json = json!({
+        "total_count": 2,
+        "entries": [
+            {
+                "type": "folder",
+                "id": "4",
+                "etag": "1",
+                "name": "Subfolder",
+                "sequence_id": "1"
+            },
+            {
+                "type": "hub",
+                "id": "7",
+                "name": "Projects",
+                "layout": "grid"
+            }
+        ]
+    });
+
+    let items_mock = mock(GET, "/folders/3/items")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    assert_output_json(cmd, json);
+    assert_eq!(items_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_folder_move_sets_parent() {
+    let mut cmd = cmd();
+    cmd.args([
+        "folder",
+        "3",
+        "move",
+        "--to",
+        "5",
+        "-t",
+        "access_token",
+        "--json",
+    ]);
+
+    let move_mock = mock(PUT, "/folders/3")
+        .expect_json_body(&json!({ "parent": { "id": "5" } }))
+        .return_status(200)
+        .return_json_body(&json!({ "type": "folder", "id": "3", "name": "Test Folder" }))
+        .create();
+
+    let expected = json!({
+        "type": "folder",
+        "id": "3",
+        "etag": null,
+        "name": "Test Folder",
+        "sequence_id": null
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(move_mock.times_called(), 1);
+}
@@ -0,0 +1,462 @@
+use httpmock::Method::{GET, POST, PUT};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::{assert_output_json, cmd};
+
+#[test]
+#[with_mock_server]
+fn bx_folder_metadata_create_parses_field_values() {
+    let mut cmd = cmd();
+    cmd.args(["folder", "3", "metadata", "create", "contract"]);
+    cmd.args(["customer=Acme", "amount:=1000", "regions:=[\"EU\",\"US\"]"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let create_mock = mock(POST, "/folders/3/metadata/enterprise/contract")
+        .expect_json_body(&json!({
+            "customer": "Acme",
+            "amount": 1000,
+            "regions": ["EU", "US"]
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "$parent": "folder_3",
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "$version": 0,
+            "customer": "Acme",
+            "amount": 1000,
+            "regions": ["EU", "US"]
+        }))
+        .create();
+
+    let expected = json!({
+        "$id": null,
+        "$type": null,
+        "$parent": "folder_3",
+        "$scope": "enterprise_12345",
+        "$template": "contract",
+        "$version": 0,
+        "$typeVersion": null,
+        "$canEdit": null,
+        "customer": "Acme",
+        "amount": 1000,
+        "regions": ["EU", "US"]
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(create_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_file_metadata_update_sends_json_patch_and_prints_table() {
+    let mut cmd = cmd();
+    cmd.args(["file", "6", "metadata", "update", "contract"]);
+    cmd.args([
+        "customer=Acme Corp",
+        "--remove",
+        "notes",
+        "-t",
+        "access_token",
+    ]);
+
+    let update_mock = mock(PUT, "/files/6/metadata/enterprise/contract")
+        .expect_header("content-type", "application/json-patch+json")
+        .expect_json_body(&json!([
+            { "op": "add", "path": "/customer", "value": "Acme Corp" },
+            { "op": "remove", "path": "/notes" }
+        ]))
+        .return_status(200)
+        .return_json_body(&json!({
+            "$parent": "file_6",
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "$version": 1,
+            "customer": "Acme Corp",
+            "regions": ["EU", "US"]
+        }))
+        .create();
+
+    cmd.assert().success().stdout(concat!(
+        "TEMPLATE                   FIELD     VALUE\n",
+        "enterprise_12345/contract  customer  Acme Corp\n",
+        "enterprise_12345/contract  regions   EU, US\n",
+    ));
+    assert_eq!(update_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_query_follows_markers() {
+    let mut cmd = cmd();
+    cmd.args(["metadata", "query", "--from", "enterprise_12345.contract"]);
+    cmd.args(["--where", "amount > :min", "--param", "min=1000"]);
+    cmd.args(["--order-by", "amount:desc", "-n", "1"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let query = json!({
+        "from": "enterprise_12345.contract",
+        "ancestor_folder_id": "0",
+        "query": "amount > :min",
+        "query_params": { "min": 1000 },
+        "order_by": [{ "field_key": "amount", "direction": "desc" }],
+        "limit": 1
+    });
+    let mut next_query = query.clone();
+    next_query["marker"] = json!("page2");
+
+    let first_mock = mock(POST, "/metadata_queries/execute_read")
+        .expect_json_body(&query)
+        .return_status(200)
+        .return_json_body(&json!({
+            "entries": [{ "type": "folder", "id": "3", "name": "Contracts" }],
+            "limit": 1,
+            "next_marker": "page2"
+        }))
+        .create();
+    let second_mock = mock(POST, "/metadata_queries/execute_read")
+        .expect_json_body(&next_query)
+        .return_status(200)
+        .return_json_body(&json!({
+            "entries": [{ "type": "folder", "id": "4", "name": "Renewals" }],
+            "limit": 1,
+            "next_marker": null
+        }))
+        .create();
+
+    cmd.assert().success().stdout(concat!(
+        r#"{"type":"folder","id":"3","etag":null,"name":"Contracts","sequence_id":null}"#,
+        "\n",
+        r#"{"type":"folder","id":"4","etag":null,"name":"Renewals","sequence_id":null}"#,
+        "\n",
+    ));
+    assert_eq!(first_mock.times_called(), 1);
+    assert_eq!(second_mock.times_called(), 1);
+}
+
+fn template_schema_mock() -> httpmock::Mock {
+    mock(GET, "/metadata_templates/enterprise_12345/contract/schema")
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "metadata_template",
+            "scope": "enterprise_12345",
+            "templateKey": "contract",
+            "fields": [
+                { "type": "string", "key": "customer" },
+                { "type": "float", "key": "amount" },
+                { "type": "multiSelect", "key": "regions" }
+            ]
+        }))
+        .create()
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_export_writes_csv_for_files_in_folder() {
+    let mut cmd = cmd();
+    cmd.args([
+        "metadata",
+        "export",
+        "--template",
+        "enterprise_12345.contract",
+    ]);
+    cmd.args(["--folder", "3", "-t", "access_token"]);
+
+    let schema_mock = template_schema_mock();
+    let items_mock = mock(GET, "/folders/3/items")
+        .expect_query_param("fields", "name,metadata.enterprise_12345.contract")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_count": 3,
+            "entries": [
+                {
+                    "type": "file",
+                    "id": "10",
+                    "name": "acme.pdf",
+                    "metadata": { "enterprise_12345": { "contract": {
+                        "$template": "contract",
+                        "customer": "Acme",
+                        "amount": 1000,
+                        "regions": ["EU", "US"]
+                    } } }
+                },
+                { "type": "file", "id": "11", "name": "notes.txt" },
+                { "type": "folder", "id": "9", "name": "Archive" }
+            ]
+        }))
+        .create();
+    let query_mock = mock(POST, "/metadata_queries/execute_read")
+        .return_status(200)
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout("id,name,customer,amount,regions\n10,acme.pdf,Acme,1000,EU|US\n");
+    assert_eq!(schema_mock.times_called(), 1);
+    assert_eq!(items_mock.times_called(), 1);
+    assert_eq!(query_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_export_recursive_queries_subfolders() {
+    let mut cmd = cmd();
+    cmd.args([
+        "metadata",
+        "export",
+        "--template",
+        "enterprise_12345.contract",
+        "--recursive",
+    ]);
+    cmd.args(["--folder", "3", "-t", "access_token"]);
+
+    let schema_mock = template_schema_mock();
+    let query_mock = mock(POST, "/metadata_queries/execute_read")
+        .expect_json_body(&json!({
+            "from": "enterprise_12345.contract",
+            "ancestor_folder_id": "3",
+            "fields": ["name", "metadata.enterprise_12345.contract"]
+        }))
+        .return_status(200)
+        .return_json_body(&json!({
+            "entries": [
+                {
+                    "type": "file",
+                    "id": "10",
+                    "name": "acme.pdf",
+                    "metadata": { "enterprise_12345": { "contract": {
+                        "customer": "Acme"
+                    } } }
+                },
+                {
+                    "type": "file",
+                    "id": "11",
+                    "name": "globex.pdf",
+                    "metadata": { "enterprise_12345": { "contract": {
+                        "customer": "Globex",
+                        "amount": 250.5
+                    } } }
+                }
+            ],
+            "next_marker": null
+        }))
+        .create();
+
+    cmd.assert().success().stdout(
+        "id,name,customer,amount,regions\n10,acme.pdf,Acme,,\n11,globex.pdf,Globex,250.5,\n",
+    );
+    assert_eq!(schema_mock.times_called(), 1);
+    assert_eq!(query_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_import_upserts_rows_and_reports_failures() {
+    let path = std::env::temp_dir().join("bx-metadata-import.csv");
+    std::fs::write(
+        &path,
+        "id,customer,amount,regions\n10,,1000,\n11,Globex,lots,\n",
+    )
+    .unwrap();
+    let mut cmd = cmd();
+    cmd.args(["metadata", "import", path.to_str().unwrap()]);
+    cmd.args([
+        "--template",
+        "enterprise_12345.contract",
+        "-t",
+        "access_token",
+    ]);
+
+    let schema_mock = template_schema_mock();
+    let create_mock = mock(POST, "/files/10/metadata/enterprise_12345/contract")
+        .expect_json_body(&json!({ "amount": 1000.0 }))
+        .return_status(409)
+        .return_json_body(
+            &json!({ "type": "error", "status": 409, "code": "tuple_already_exists" }),
+        )
+        .create();
+    let get_mock = mock(GET, "/files/10/metadata/enterprise_12345/contract")
+        .return_status(200)
+        .return_json_body(&json!({
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "customer": "Acme",
+            "amount": 10
+        }))
+        .create();
+    let update_mock = mock(PUT, "/files/10/metadata/enterprise_12345/contract")
+        .expect_json_body(&json!([
+            { "op": "add", "path": "/amount", "value": 1000.0 },
+            { "op": "remove", "path": "/customer" }
+        ]))
+        .return_status(200)
+        .return_json_body(&json!({
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "amount": 1000
+        }))
+        .create();
+
+    cmd.assert()
+        .failure()
+        .stdout(predicates::str::contains("Row 2: file 10 updated"))
+        .stderr(predicates::str::contains(
+            "Row 3: amount is not a number: lots",
+        ));
+    assert_eq!(schema_mock.times_called(), 1);
+    assert_eq!(create_mock.times_called(), 1);
+    assert_eq!(get_mock.times_called(), 1);
+    assert_eq!(update_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_import_dry_run_resolves_paths_without_changes() {
+    let path = std::env::temp_dir().join("bx-metadata-import-dry-run.csv");
+    std::fs::write(
+        &path,
+        "path,customer\nContracts/acme.pdf,Acme\nContracts/globex.pdf,\n",
+    )
+    .unwrap();
+    let mut cmd = cmd();
+    cmd.args(["metadata", "import", path.to_str().unwrap(), "--dry-run"]);
+    cmd.args([
+        "--template",
+        "enterprise_12345.contract",
+        "-t",
+        "access_token",
+    ]);
+
+    let schema_mock = template_schema_mock();
+    let root_mock = mock(GET, "/folders/0/items")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{ "type": "folder", "id": "3", "name": "Contracts" }]
+        }))
+        .create();
+    let folder_mock = mock(GET, "/folders/3/items")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_count": 2,
+            "entries": [
+                { "type": "file", "id": "10", "name": "acme.pdf" },
+                { "type": "file", "id": "11", "name": "globex.pdf" }
+            ]
+        }))
+        .create();
+    let acme_mock = mock(GET, "/files/10/metadata/enterprise_12345/contract")
+        .return_status(404)
+        .return_json_body(&json!({ "type": "error", "status": 404 }))
+        .create();
+    let globex_mock = mock(GET, "/files/11/metadata/enterprise_12345/contract")
+        .return_status(200)
+        .return_json_body(&json!({
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "customer": "Globex"
+        }))
+        .create();
+    let create_mock = mock(POST, "/files/10/metadata/enterprise_12345/contract")
+        .return_status(201)
+        .create();
+    let update_mock = mock(PUT, "/files/11/metadata/enterprise_12345/contract")
+        .return_status(200)
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains(
+            r#"Row 2: would create metadata on file 10 with {"customer":"Acme"}"#,
+        ))
+        .stdout(predicates::str::contains(
+            r#"Row 3: would update metadata on file 11 with [{"op":"remove","path":"/customer"}]"#,
+        ));
+    assert_eq!(schema_mock.times_called(), 1);
+    assert_eq!(root_mock.times_called(), 1);
+    assert_eq!(folder_mock.times_called(), 1);
+    assert_eq!(acme_mock.times_called(), 1);
+    assert_eq!(globex_mock.times_called(), 1);
+    assert_eq!(create_mock.times_called(), 0);
+    assert_eq!(update_mock.times_called(), 0);
+    std::fs::remove_file(&path).unwrap();
+}
+
+fn enterprise_templates_mock() -> httpmock::Mock {
+    mock(GET, "/metadata_templates/enterprise")
+        .return_status(200)
+        .return_json_body(&json!({
+            "entries": [
+                {
+                    "type": "metadata_template",
+                    "scope": "enterprise_12345",
+                    "templateKey": "contract",
+                    "fields": [
+                        { "type": "string", "key": "customer" },
+                        { "type": "float", "key": "amount" }
+                    ]
+                },
+                {
+                    "type": "metadata_template",
+                    "scope": "enterprise_12345",
+                    "templateKey": "invoice",
+                    "fields": [
+                        { "type": "string", "key": "customer" },
+                        { "type": "date", "key": "due" }
+                    ]
+                }
+            ],
+            "next_marker": null
+        }))
+        .create()
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_import_infers_template_from_columns() {
+    let path = std::env::temp_dir().join("bx-metadata-import-infer.csv");
+    std::fs::write(&path, "id,name,customer,amount\n10,acme.pdf,Acme,1000\n").unwrap();
+    let mut cmd = cmd();
+    cmd.args(["metadata", "import", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let templates_mock = enterprise_templates_mock();
+    let create_mock = mock(POST, "/files/10/metadata/enterprise_12345/contract")
+        .expect_json_body(&json!({ "customer": "Acme", "amount": 1000.0 }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "$scope": "enterprise_12345",
+            "$template": "contract",
+            "customer": "Acme",
+            "amount": 1000
+        }))
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Row 2: file 10 updated"));
+    assert_eq!(templates_mock.times_called(), 1);
+    assert_eq!(create_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_import_asks_for_template_when_columns_are_ambiguous() {
+    let path = std::env::temp_dir().join("bx-metadata-import-ambiguous.csv");
+    std::fs::write(&path, "id,customer\n10,Acme\n").unwrap();
+    let mut cmd = cmd();
+    cmd.args(["metadata", "import", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token"]);
+
+    let templates_mock = enterprise_templates_mock();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "the CSV's columns match several templates \
+         (enterprise_12345.contract, enterprise_12345.invoice); use --template",
+    ));
+    assert_eq!(templates_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,134 @@
+use httpmock::Method::{POST, PUT};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::cmd;
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_template_create_reads_yaml_definition() {
+    let path = std::env::temp_dir().join("bx-metadata-template-contract.yaml");
+    std::fs::write(
+        &path,
+        concat!(
+            "displayName: Contract\n",
+            "templateKey: contract\n",
+            "fields:\n",
+            "  - type: string\n",
+            "    key: customer\n",
+            "    displayName: Customer\n",
+            "  - type: enum\n",
+            "    key: region\n",
+            "    displayName: Region\n",
+            "    options:\n",
+            "      - key: EU\n",
+            "      - key: US\n",
+        ),
+    )
+    .unwrap();
+    let mut cmd = cmd();
+    cmd.args(["metadata-template", "create", path.to_str().unwrap()]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let create_mock = mock(POST, "/metadata_templates/schema")
+        .expect_json_body(&json!({
+            "scope": "enterprise",
+            "templateKey": "contract",
+            "displayName": "Contract",
+            "fields": [
+                { "type": "string", "key": "customer", "displayName": "Customer" },
+                {
+                    "type": "enum",
+                    "key": "region",
+                    "displayName": "Region",
+                    "options": [{ "key": "EU" }, { "key": "US" }]
+                }
+            ]
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "metadata_template",
+            "id": "58063d82-4128-7b43-bba9-92f706befcdf",
+            "scope": "enterprise_12345",
+            "templateKey": "contract",
+            "displayName": "Contract"
+        }))
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("\"templateKey\":\"contract\""));
+    assert_eq!(create_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_template_update_sends_field_operations() {
+    let mut cmd = cmd();
+    cmd.args(["metadata-template", "update", "contract"]);
+    cmd.args(["--reorder-fields", "region,customer"]);
+    cmd.args(["--add-enum-option", "region", "APAC"]);
+    cmd.args(["--edit-enum-option", "region", "US", "NA"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let update_mock = mock(PUT, "/metadata_templates/enterprise/contract/schema")
+        .expect_json_body(&json!([
+            { "op": "reorderFields", "fieldKeys": ["region", "customer"] },
+            { "op": "addEnumOption", "fieldKey": "region", "data": { "key": "APAC" } },
+            {
+                "op": "editEnumOption",
+                "fieldKey": "region",
+                "enumOptionKey": "US",
+                "data": { "key": "NA" }
+            }
+        ]))
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "metadata_template",
+            "scope": "enterprise_12345",
+            "templateKey": "contract",
+            "displayName": "Contract"
+        }))
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(update_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_metadata_template_update_keeps_command_line_order() {
+    let mut cmd = cmd();
+    cmd.args(["metadata-template", "update", "contract"]);
+    cmd.args(["--add-enum-option", "region", "APAC"]);
+    cmd.args(["--edit-enum-option", "region", "APAC", "AsiaPac"]);
+    cmd.args(["--remove-enum-option", "region", "EU"]);
+    cmd.args(["--add-enum-option", "region", "EU"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let update_mock = mock(PUT, "/metadata_templates/enterprise/contract/schema")
+        .expect_json_body(&json!([
+            { "op": "addEnumOption", "fieldKey": "region", "data": { "key": "APAC" } },
+            {
+                "op": "editEnumOption",
+                "fieldKey": "region",
+                "enumOptionKey": "APAC",
+                "data": { "key": "AsiaPac" }
+            },
+            { "op": "removeEnumOption", "fieldKey": "region", "enumOptionKey": "EU" },
+            { "op": "addEnumOption", "fieldKey": "region", "data": { "key": "EU" } }
+        ]))
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "metadata_template",
+            "scope": "enterprise_12345",
+            "templateKey": "contract",
+            "displayName": "Contract"
+        }))
+        .create();
+
+    cmd.assert().success();
+    assert_eq!(update_mock.times_called(), 1);
+}
@@ -0,0 +1,44 @@
+use bx::sdk::models::UserStatus;
+use bx::sdk::Client;
+use httpmock::Method::GET;
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+#[test]
+#[with_mock_server]
+fn sdk_exposes_model_accessors() {
+    std::env::set_var("BOX_API_ROOT", "http://localhost:5000");
+
+    let json = json!({
+        "type": "user",
+        "id": "1",
+        "name": "Test User",
+        "login": "user@example.com",
+        "status": "active",
+        "space_used": 1024,
+        "is_platform_access_only": false
+    });
+
+    let user_mock = mock(GET, "/users/me")
+        .expect_header("authorization", "Bearer access_token")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    let mut runtime = tokio::runtime::Runtime::new().unwrap();
+    let user = runtime
+        .block_on(async {
+            let mut client = Client::new("access_token".to_owned());
+            client.user("me").get().await
+        })
+        .unwrap();
+
+    assert_eq!(user.id(), "1");
+    assert_eq!(user.name(), Some("Test User"));
+    assert_eq!(user.login(), Some("user@example.com"));
+    assert_eq!(user.status(), Some(&UserStatus::Active));
+    assert_eq!(user.space_used(), Some(1024));
+    assert_eq!(user.role(), None);
+    assert_eq!(user.extra()["is_platform_access_only"], json!(false));
+    assert_eq!(user_mock.times_called(), 1);
+}
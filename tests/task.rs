@@ -0,0 +1,101 @@
+use httpmock::Method::{POST, PUT};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::{assert_output_json, cmd};
+
+#[test]
+#[with_mock_server]
+fn bx_task_assign_by_login() {
+    let mut cmd = cmd();
+    cmd.args(["task", "12", "assign", "--login", "jane@example.com"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let assign_mock = mock(POST, "/task_assignments")
+        .expect_json_body(&json!({
+            "task": { "type": "task", "id": "12" },
+            "assign_to": { "login": "jane@example.com" }
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "task_assignment",
+            "id": "40",
+            "resolution_state": "incomplete"
+        }))
+        .create();
+
+    let expected = json!({
+        "type": "task_assignment",
+        "id": "40",
+        "item": null,
+        "assigned_to": null,
+        "assigned_by": null,
+        "message": null,
+        "resolution_state": "incomplete",
+        "assigned_at": null,
+        "reminded_at": null,
+        "completed_at": null
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(assign_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_task_resolve_sends_resolution_state() {
+    let mut cmd = cmd();
+    cmd.args([
+        "task",
+        "resolve",
+        "40",
+        "approved",
+        "--message",
+        "Looks good",
+    ]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let resolve_mock = mock(PUT, "/task_assignments/40")
+        .expect_json_body(&json!({
+            "resolution_state": "approved",
+            "message": "Looks good"
+        }))
+        .return_status(200)
+        .return_json_body(&json!({
+            "type": "task_assignment",
+            "id": "40",
+            "message": "Looks good",
+            "resolution_state": "approved"
+        }))
+        .create();
+
+    let expected = json!({
+        "type": "task_assignment",
+        "id": "40",
+        "item": null,
+        "assigned_to": null,
+        "assigned_by": null,
+        "message": "Looks good",
+        "resolution_state": "approved",
+        "assigned_at": null,
+        "reminded_at": null,
+        "completed_at": null
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(resolve_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_task_create_rejects_out_of_range_due_date() {
+    let mut cmd = cmd();
+    cmd.args(["task", "create", "--file", "6", "--due", "99999999999d"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let create_mock = mock(POST, "/tasks").create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "99999999999d from now is too far off",
+    ));
+    assert_eq!(create_mock.times_called(), 0);
+}
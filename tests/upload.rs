@@ -0,0 +1,541 @@
+use httpmock::Method::{DELETE, GET, OPTIONS, POST, PUT};
+use httpmock::{mock, with_mock_server, Mock};
+use serde_json::json;
+use std::path::PathBuf;
+
+mod common;
+use common::{assert_output_json, cmd, file_json};
+
+const CONTENT: &str = "Hello, Box!\n";
+
+fn write_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, CONTENT).unwrap();
+    path
+}
+
+/// The parts Box records for `CONTENT` uploaded with a part size of 5.
+fn uploaded_parts() -> Vec<serde_json::Value> {
+    vec![
+        json!({ "part_id": "6F2D3486", "offset": 0, "size": 5, "sha1": "f7ff9e8b7bb2e09b70935a5d785e0cc5d9d0abf0" }),
+        json!({ "part_id": "BFDF5379", "offset": 5, "size": 5, "sha1": "8d8c52cb42df84e6980176bf483dd906d76e3fd3" }),
+        json!({ "part_id": "1A2B3C4D", "offset": 10, "size": 2, "sha1": "c93d958878f48e29765c96917f63d5489072cab1" }),
+    ]
+}
+
+/// Mocks the upload of each part of `CONTENT`, answering with the part that
+/// matches the offset in the request's Content-Range.
+fn part_mocks(session_id: &str) -> Vec<Mock> {
+    uploaded_parts()
+        .into_iter()
+        .map(|part| {
+            let offset = part["offset"].as_u64().unwrap();
+            let end = offset + part["size"].as_u64().unwrap() - 1;
+            mock(PUT, &format!("/files/upload_sessions/{}", session_id))
+                .expect_header("authorization", "Bearer access_token")
+                .expect_header("content-range", &format!("bytes {}-{}/12", offset, end))
+                .expect_header_exists("digest")
+                .return_status(200)
+                .return_json_body(&json!({ "part": part }))
+                .create()
+        })
+        .collect()
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_uploads_small_file() {
+    let path = write_file("bx-upload-small.txt");
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "-D", "3"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let upload_mock = mock(POST, "/files/content")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_header("content-md5", "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d")
+        .expect_body_contains("\"name\":\"bx-upload-small.txt\"")
+        .expect_body_contains(CONTENT)
+        .return_status(201)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{ "type": "file", "id": "7", "name": "bx-upload-small.txt" }]
+        }))
+        .create();
+
+    let expected = file_json(json!({ "id": "7", "name": "bx-upload-small.txt" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(upload_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_reports_missing_file_in_response() {
+    let path = write_file("bx-upload-empty-response.txt");
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "-D", "3"]);
+    cmd.args(["-t", "access_token"]);
+
+    let upload_mock = mock(POST, "/files/content")
+        .return_status(201)
+        .return_json_body(&json!({ "total_count": 0, "entries": [] }))
+        .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "bx-upload-empty-response.txt was uploaded but no file was returned",
+    ));
+    assert_eq!(upload_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_uses_upload_session_above_threshold() {
+    let path = write_file("bx-upload-chunked.txt");
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "-D", "3"]);
+    cmd.args(["--chunked-threshold", "4", "--concurrency", "2"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let preflight_mock = mock(OPTIONS, "/files/content")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_json_body(&json!({
+            "name": "bx-upload-chunked.txt",
+            "parent": { "id": "3" },
+            "size": 12
+        }))
+        .return_status(200)
+        .return_json_body(&json!({ "upload_url": "https://upload.box.com/api/2.0/files/content" }))
+        .create();
+
+    let session_mock = mock(POST, "/files/upload_sessions")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_json_body(&json!({
+            "folder_id": "3",
+            "file_name": "bx-upload-chunked.txt",
+            "file_size": 12
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "upload_session",
+            "id": "F971964745A5CD0C001BBE4E58196BFD",
+            "part_size": 5,
+            "total_parts": 3,
+            "num_parts_processed": 0
+        }))
+        .create();
+
+    let part_mocks = part_mocks("F971964745A5CD0C001BBE4E58196BFD");
+
+    let commit_mock = mock(
+        POST,
+        "/files/upload_sessions/F971964745A5CD0C001BBE4E58196BFD/commit",
+    )
+    .expect_header("authorization", "Bearer access_token")
+    .expect_header("digest", "sha=EXiY0NN7c700gP33keak/iPsPQ0=")
+    .expect_json_body(&json!({ "parts": uploaded_parts() }))
+    .return_status(201)
+    .return_json_body(&json!({
+        "total_count": 1,
+        "entries": [{ "type": "file", "id": "8", "name": "bx-upload-chunked.txt", "size": 12 }]
+    }))
+    .create();
+
+    let abort_mock = mock(
+        DELETE,
+        "/files/upload_sessions/F971964745A5CD0C001BBE4E58196BFD",
+    )
+    .return_status(204)
+    .create();
+
+    let expected = file_json(json!({ "id": "8", "name": "bx-upload-chunked.txt", "size": 12 }));
+    assert_output_json(cmd, expected);
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(session_mock.times_called(), 1);
+    for part_mock in part_mocks {
+        assert_eq!(part_mock.times_called(), 1);
+    }
+    assert_eq!(commit_mock.times_called(), 1);
+    assert_eq!(abort_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_reads_stdin() {
+    let mut cmd = cmd();
+    cmd.args(["upload", "-", "--name", "stdin.txt", "-D", "3"]);
+    cmd.args(["-t", "access_token", "--json"]);
+    cmd.write_stdin(CONTENT);
+
+    let upload_mock = mock(POST, "/files/content")
+        .expect_header("content-md5", "117898d0d37b73bd3480fdf791e6a4fe23ec3d0d")
+        .expect_body_contains("\"name\":\"stdin.txt\"")
+        .expect_body_contains(CONTENT)
+        .return_status(201)
+        .return_json_body(&json!({
+            "total_count": 1,
+            "entries": [{ "type": "file", "id": "7", "name": "stdin.txt" }]
+        }))
+        .create();
+
+    let expected = file_json(json!({ "id": "7", "name": "stdin.txt" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(upload_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_sends_large_stdin_through_upload_session() {
+    let mut cmd = cmd();
+    cmd.args(["upload", "-", "--name", "stdin.txt", "-D", "3"]);
+    cmd.args(["--chunked-threshold", "4", "-t", "access_token", "--json"]);
+    cmd.write_stdin(CONTENT);
+
+    let preflight_mock = mock(OPTIONS, "/files/content")
+        .expect_json_body(&json!({
+            "name": "stdin.txt",
+            "parent": { "id": "3" },
+            "size": 12
+        }))
+        .return_status(200)
+        .return_json_body(&json!({ "upload_url": "https://upload.box.com/api/2.0/files/content" }))
+        .create();
+
+    let session_mock = mock(POST, "/files/upload_sessions")
+        .expect_json_body(&json!({
+            "folder_id": "3",
+            "file_name": "stdin.txt",
+            "file_size": 12
+        }))
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "upload_session",
+            "id": "0B3D4F3B8E1B4C9D9C3E0C1A3B2F4E5D",
+            "part_size": 5,
+            "total_parts": 3,
+            "num_parts_processed": 0
+        }))
+        .create();
+
+    let part_mocks = part_mocks("0B3D4F3B8E1B4C9D9C3E0C1A3B2F4E5D");
+
+    let commit_mock = mock(
+        POST,
+        "/files/upload_sessions/0B3D4F3B8E1B4C9D9C3E0C1A3B2F4E5D/commit",
+    )
+    .expect_header("digest", "sha=EXiY0NN7c700gP33keak/iPsPQ0=")
+    .expect_json_body(&json!({ "parts": uploaded_parts() }))
+    .return_status(201)
+    .return_json_body(&json!({
+        "total_count": 1,
+        "entries": [{ "type": "file", "id": "8", "name": "stdin.txt", "size": 12 }]
+    }))
+    .create();
+
+    let expected = file_json(json!({ "id": "8", "name": "stdin.txt", "size": 12 }));
+    assert_output_json(cmd, expected);
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(session_mock.times_called(), 1);
+    for part_mock in part_mocks {
+        assert_eq!(part_mock.times_called(), 1);
+    }
+    assert_eq!(commit_mock.times_called(), 1);
+}
+
+fn write_upload_state(path: &PathBuf, file_size: u64, parts: serde_json::Value) {
+    let modified = std::fs::metadata(path).unwrap().modified().unwrap();
+    let state = json!({
+        "session_id": "D5E3F8ADA11A38F0A66AD0B64AACA658",
+        "part_size": 5,
+        "file_size": file_size,
+        "modified": modified,
+        "parts": parts
+    });
+    let mut state_path = path.clone().into_os_string();
+    state_path.push(".bx-upload");
+    std::fs::write(state_path, state.to_string()).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_resume_uploads_missing_parts() {
+    let path = write_file("bx-upload-resume.txt");
+    let first_part = uploaded_parts()[0].clone();
+    write_upload_state(&path, 12, json!([first_part]));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let list_mock = mock(
+        GET,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/parts",
+    )
+    .expect_header("authorization", "Bearer access_token")
+    .return_status(200)
+    .return_json_body(&json!({
+        "total_count": 1,
+        "limit": 1000,
+        "offset": 0,
+        "entries": [first_part]
+    }))
+    .create();
+
+    let part_mocks = part_mocks("D5E3F8ADA11A38F0A66AD0B64AACA658");
+
+    let commit_mock = mock(
+        POST,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/commit",
+    )
+    .expect_header("digest", "sha=EXiY0NN7c700gP33keak/iPsPQ0=")
+    .expect_json_body(&json!({ "parts": uploaded_parts() }))
+    .return_status(201)
+    .return_json_body(&json!({
+        "total_count": 1,
+        "entries": [{ "type": "file", "id": "9", "name": "bx-upload-resume.txt" }]
+    }))
+    .create();
+
+    let expected = file_json(json!({ "id": "9", "name": "bx-upload-resume.txt" }));
+    assert_output_json(cmd, expected);
+    assert_eq!(list_mock.times_called(), 1);
+    assert_eq!(part_mocks[0].times_called(), 0);
+    assert_eq!(part_mocks[1].times_called(), 1);
+    assert_eq!(part_mocks[2].times_called(), 1);
+    assert_eq!(commit_mock.times_called(), 1);
+    assert!(!std::env::temp_dir()
+        .join("bx-upload-resume.txt.bx-upload")
+        .exists());
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_resume_refuses_changed_file() {
+    let path = write_file("bx-upload-changed.txt");
+    write_upload_state(&path, 20, json!([]));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "bx-upload-changed.txt has changed since the upload was started",
+    ));
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_check_only_reports_conflict() {
+    let path = write_file("bx-upload-conflict.txt");
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "-D", "3", "--check-only"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let preflight_mock = mock(OPTIONS, "/files/content")
+        .expect_header("authorization", "Bearer access_token")
+        .return_status(409)
+        .return_json_body(&json!({
+            "type": "error",
+            "status": 409,
+            "code": "item_name_in_use",
+            "message": "Item with the same name already exists",
+            "request_id": "abcdef123456"
+        }))
+        .create();
+
+    let upload_mock = mock(POST, "/files/content").create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "upload rejected: Item with the same name already exists",
+    ));
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(upload_mock.times_called(), 0);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_gives_up_when_commit_stays_accepted() {
+    let path = write_file("bx-upload-processing.txt");
+    write_upload_state(&path, 12, json!(uploaded_parts()));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let list_mock = mock(
+        GET,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/parts",
+    )
+    .return_status(200)
+    .return_json_body(&json!({
+        "total_count": 3,
+        "limit": 1000,
+        "offset": 0,
+        "entries": uploaded_parts()
+    }))
+    .create();
+
+    let commit_mock = mock(
+        POST,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/commit",
+    )
+    .return_status(202)
+    .return_header("Retry-After", "0")
+    .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "upload session D5E3F8ADA11A38F0A66AD0B64AACA658 was still processing after 60 commit attempts",
+    ));
+    assert_eq!(list_mock.times_called(), 1);
+    assert_eq!(commit_mock.times_called(), 60);
+}
+
+/// Mocks the session of a resumed upload whose parts are all uploaded, with a
+/// commit that fails with `status`.
+fn failing_commit_mocks(status: usize) -> (Mock, Mock) {
+    mock(
+        GET,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/parts",
+    )
+    .return_status(200)
+    .return_json_body(&json!({
+        "total_count": 3,
+        "limit": 1000,
+        "offset": 0,
+        "entries": uploaded_parts()
+    }))
+    .create();
+
+    let commit_mock = mock(
+        POST,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658/commit",
+    )
+    .return_status(status)
+    .return_json_body(&json!({
+        "type": "error",
+        "status": status,
+        "code": "failed",
+        "message": "The commit failed"
+    }))
+    .create();
+
+    let abort_mock = mock(
+        DELETE,
+        "/files/upload_sessions/D5E3F8ADA11A38F0A66AD0B64AACA658",
+    )
+    .return_status(204)
+    .create();
+
+    (commit_mock, abort_mock)
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_keeps_state_when_commit_fails_on_server() {
+    let path = write_file("bx-upload-server-error.txt");
+    write_upload_state(&path, 12, json!(uploaded_parts()));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let (commit_mock, abort_mock) = failing_commit_mocks(503);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("box api error: 503"));
+    assert_eq!(commit_mock.times_called(), 1);
+    assert_eq!(abort_mock.times_called(), 0);
+    assert!(std::env::temp_dir()
+        .join("bx-upload-server-error.txt.bx-upload")
+        .exists());
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_keeps_state_when_commit_is_rate_limited() {
+    let path = write_file("bx-upload-rate-limited.txt");
+    write_upload_state(&path, 12, json!(uploaded_parts()));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let (commit_mock, abort_mock) = failing_commit_mocks(429);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("box api error: 429"));
+    assert_eq!(commit_mock.times_called(), 1);
+    assert_eq!(abort_mock.times_called(), 0);
+    assert!(std::env::temp_dir()
+        .join("bx-upload-rate-limited.txt.bx-upload")
+        .exists());
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_aborts_when_commit_is_rejected() {
+    let path = write_file("bx-upload-rejected.txt");
+    write_upload_state(&path, 12, json!(uploaded_parts()));
+
+    let mut cmd = cmd();
+    cmd.args(["upload", path.to_str().unwrap(), "--resume"]);
+    cmd.args(["-t", "access_token", "--json"]);
+
+    let (commit_mock, abort_mock) = failing_commit_mocks(412);
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("box api error: 412"));
+    assert_eq!(commit_mock.times_called(), 1);
+    assert_eq!(abort_mock.times_called(), 1);
+    assert!(!std::env::temp_dir()
+        .join("bx-upload-rejected.txt.bx-upload")
+        .exists());
+}
+
+#[test]
+#[with_mock_server]
+fn bx_upload_aborts_stdin_session_when_parts_fail() {
+    let mut cmd = cmd();
+    cmd.args(["upload", "-", "--name", "../stdin.txt", "--size", "12"]);
+    cmd.args(["-D", "3", "--chunked-threshold", "4"]);
+    cmd.args(["-t", "access_token", "--json"]);
+    cmd.write_stdin(CONTENT);
+
+    let preflight_mock = mock(OPTIONS, "/files/content")
+        .return_status(200)
+        .return_json_body(&json!({ "upload_url": "https://upload.box.com/api/2.0/files/content" }))
+        .create();
+
+    let session_mock = mock(POST, "/files/upload_sessions")
+        .return_status(201)
+        .return_json_body(&json!({
+            "type": "upload_session",
+            "id": "7A1C2E3F4B5D6E7F8A9B0C1D2E3F4A5B",
+            "part_size": 5,
+            "total_parts": 3,
+            "num_parts_processed": 0
+        }))
+        .create();
+
+    mock(
+        PUT,
+        "/files/upload_sessions/7A1C2E3F4B5D6E7F8A9B0C1D2E3F4A5B",
+    )
+    .return_status(500)
+    .create();
+
+    let abort_mock = mock(
+        DELETE,
+        "/files/upload_sessions/7A1C2E3F4B5D6E7F8A9B0C1D2E3F4A5B",
+    )
+    .return_status(204)
+    .create();
+
+    cmd.assert()
+        .failure()
+        .stderr(predicates::str::contains("box api error: 500"));
+    assert_eq!(preflight_mock.times_called(), 1);
+    assert_eq!(session_mock.times_called(), 1);
+    assert_eq!(abort_mock.times_called(), 1);
+}
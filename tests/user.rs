@@ -25,9 +25,26 @@ fn bx_user_displays_current_user() {
         .create();
 
     let expected = json!({
+        "type": "user",
         "id": "1",
         "name": "Test User",
         "login": "user@example.com",
+        "created_at": null,
+        "modified_at": null,
+        "language": null,
+        "timezone": null,
+        "space_amount": null,
+        "space_used": null,
+        "max_upload_size": null,
+        "status": null,
+        "role": null,
+        "job_title": null,
+        "phone": null,
+        "address": null,
+        "avatar_url": null,
+        "enterprise": null,
+        "tracking_codes": null,
+        "notification_email": null,
     });
     assert_output_json(cmd, expected);
     assert_eq!(search_mock.times_called(), 1);
@@ -53,9 +70,135 @@ fn bx_user_id_displays_user() {
         .create();
 
     let expected = json!({
+        "type": "user",
         "id": "2",
         "name": "Test User 2",
         "login": "user2@example.com",
+        "created_at": null,
+        "modified_at": null,
+        "language": null,
+        "timezone": null,
+        "space_amount": null,
+        "space_used": null,
+        "max_upload_size": null,
+        "status": null,
+        "role": null,
+        "job_title": null,
+        "phone": null,
+        "address": null,
+        "avatar_url": null,
+        "enterprise": null,
+        "tracking_codes": null,
+        "notification_email": null,
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(search_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_users_list_displays_enterprise_users() {
+    let mut cmd = cmd();
+    cmd.args([
+        "users",
+        "list",
+        "--filter",
+        "jane",
+        "--type",
+        "managed",
+        "-n",
+        "2",
+        "-t",
+        "access_token",
+        "--json",
+    ]);
+
+    let json = json!({
+        "total_count": 1,
+        "limit": 2,
+        "offset": 0,
+        "entries": [{
+            "type": "user",
+            "id": "3",
+            "name": "Jane Doe",
+            "login": "jane@example.com",
+            "created_at": "2020-05-01T10:00:00-07:00",
+            "modified_at": "2020-05-02T10:00:00-07:00",
+            "language": "en",
+            "timezone": "America/Los_Angeles",
+            "space_amount": 11_345_156_112_i64,
+            "space_used": 1_237_009_912,
+            "max_upload_size": 2_147_483_648_u64,
+            "status": "active",
+            "role": "coadmin",
+            "job_title": "CEO",
+            "phone": "6509241374",
+            "address": "900 Jefferson Ave, Redwood City, CA 94063",
+            "avatar_url": "https://www.box.com/api/avatar/large/181216415",
+            "enterprise": {
+                "type": "enterprise",
+                "id": "11446498",
+                "name": "Acme Inc."
+            },
+            "tracking_codes": [{
+                "type": "tracking_code",
+                "name": "department",
+                "value": "Sales"
+            }],
+            "notification_email": {
+                "email": "notifications@example.com",
+                "is_confirmed": true
+            }
+        }]
+    });
+
+    let search_mock = mock(GET, "/users")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_query_param("filter_term", "jane")
+        .expect_query_param("user_type", "managed")
+        .expect_query_param("limit", "2")
+        .expect_query_param("offset", "0")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    let expected = json!({
+        "total_count": 1,
+        "limit": 2,
+        "offset": 0,
+        "entries": [{
+            "type": "user",
+            "id": "3",
+            "name": "Jane Doe",
+            "login": "jane@example.com",
+            "created_at": "2020-05-01T10:00:00-07:00",
+            "modified_at": "2020-05-02T10:00:00-07:00",
+            "language": "en",
+            "timezone": "America/Los_Angeles",
+            "space_amount": 11_345_156_112_i64,
+            "space_used": 1_237_009_912,
+            "max_upload_size": 2_147_483_648_u64,
+            "status": "active",
+            "role": "coadmin",
+            "job_title": "CEO",
+            "phone": "6509241374",
+            "address": "900 Jefferson Ave, Redwood City, CA 94063",
+            "avatar_url": "https://www.box.com/api/avatar/large/181216415",
+            "enterprise": {
+                "type": "enterprise",
+                "id": "11446498",
+                "name": "Acme Inc."
+            },
+            "tracking_codes": [{
+                "type": "tracking_code",
+                "name": "department",
+                "value": "Sales"
+            }],
+            "notification_email": {
+                "email": "notifications@example.com",
+                "is_confirmed": true
+            }
+        }]
     });
     assert_output_json(cmd, expected);
     assert_eq!(search_mock.times_called(), 1);
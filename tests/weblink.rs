@@ -0,0 +1,126 @@
+use httpmock::Method::{GET, POST};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::{assert_output_json, cmd};
+
+#[test]
+#[with_mock_server]
+fn bx_weblink_id_displays_web_link() {
+    let mut cmd = cmd();
+    cmd.args(["weblink", "4", "-t", "access_token", "--json"]);
+
+    let json = json!({
+        "type": "web_link",
+        "id": "4",
+        "etag": "1",
+        "name": "Example",
+        "sequence_id": "1",
+        "url": "https://example.com",
+        "description": "An example site"
+    });
+
+    let search_mock = mock(GET, "/web_links/4")
+        .expect_header("authorization", "Bearer access_token")
+        .return_status(200)
+        .return_json_body(&json)
+        .create();
+
+    let expected = json!({
+        "type": "web_link",
+        "id": "4",
+        "etag": "1",
+        "name": "Example",
+        "sequence_id": "1",
+        "url": "https://example.com",
+        "description": "An example site",
+        "created_at": null,
+        "created_by": null,
+        "item_status": null,
+        "modified_at": null,
+        "modified_by": null,
+        "owned_by": null,
+        "parent": null,
+        "path_collection": null,
+        "purged_at": null,
+        "shared_link": null,
+        "trashed_at": null
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(search_mock.times_called(), 1);
+}
+
+#[test]
+#[with_mock_server]
+fn bx_weblink_create_creates_web_link() {
+    let mut cmd = cmd();
+    cmd.args([
+        "weblink",
+        "create",
+        "https://example.com",
+        "-D",
+        "3",
+        "--name",
+        "Example",
+        "-t",
+        "access_token",
+        "--json",
+    ]);
+
+    let json = json!({
+        "type": "web_link",
+        "id": "5",
+        "etag": "0",
+        "name": "Example",
+        "sequence_id": "0",
+        "url": "https://example.com",
+        "parent": {
+            "type": "folder",
+            "id": "3",
+            "etag": "1",
+            "name": "Test Folder",
+            "sequence_id": "1"
+        }
+    });
+
+    let create_mock = mock(POST, "/web_links")
+        .expect_header("authorization", "Bearer access_token")
+        .expect_json_body(&json!({
+            "url": "https://example.com",
+            "parent": { "id": "3" },
+            "name": "Example"
+        }))
+        .return_status(201)
+        .return_json_body(&json)
+        .create();
+
+    let expected = json!({
+        "type": "web_link",
+        "id": "5",
+        "etag": "0",
+        "name": "Example",
+        "sequence_id": "0",
+        "url": "https://example.com",
+        "description": null,
+        "created_at": null,
+        "created_by": null,
+        "item_status": null,
+        "modified_at": null,
+        "modified_by": null,
+        "owned_by": null,
+        "parent": {
+            "type": "folder",
+            "id": "3",
+            "etag": "1",
+            "name": "Test Folder",
+            "sequence_id": "1"
+        },
+        "path_collection": null,
+        "purged_at": null,
+        "shared_link": null,
+        "trashed_at": null
+    });
+    assert_output_json(cmd, expected);
+    assert_eq!(create_mock.times_called(), 1);
+}
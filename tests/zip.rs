@@ -0,0 +1,121 @@
+use httpmock::Method::{GET, POST};
+use httpmock::{mock, with_mock_server};
+use serde_json::json;
+
+mod common;
+use common::cmd;
+
+#[test]
+#[with_mock_server]
+fn bx_zip_downloads_archive_and_reports_skipped_items() {
+    let path = std::env::temp_dir().join("bx-zip-bundle.zip");
+    let _ = std::fs::remove_file(&path);
+    let mut cmd = cmd();
+    cmd.args(["zip", "--file", "1", "--file", "2", "--folder", "3"]);
+    cmd.args(["-o", path.to_str().unwrap(), "-t", "access_token"]);
+
+    let create_mock = mock(POST, "/zip_downloads")
+        .expect_json_body(&json!({
+            "items": [
+                { "type": "file", "id": "1" },
+                { "type": "file", "id": "2" },
+                { "type": "folder", "id": "3" }
+            ],
+            "download_file_name": "bx-zip-bundle"
+        }))
+        .return_status(202)
+        .return_json_body(&json!({
+            "download_url": "http://localhost:5000/zip_downloads/abc/content",
+            "status_url": "http://localhost:5000/zip_downloads/abc/status",
+            "expires_at": "2020-05-01T12:00:00-07:00",
+            "name_conflicts": []
+        }))
+        .create();
+
+    let content_mock = mock(GET, "/zip_downloads/abc/content")
+        .return_status(200)
+        .return_body("PK zip content")
+        .create();
+
+    let status_mock = mock(GET, "/zip_downloads/abc/status")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_file_count": 5,
+            "downloaded_file_count": 4,
+            "skipped_file_count": 1,
+            "skipped_folder_count": 0,
+            "state": "succeeded"
+        }))
+        .create();
+
+    cmd.assert()
+        .success()
+        .stdout(predicates::str::contains("Downloaded 4 of 5 files to "))
+        .stdout(predicates::str::contains("skipped 1 files and 0 folders"));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "PK zip content");
+    assert_eq!(create_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    assert_eq!(status_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_zip_refuses_existing_output_before_creating_archive() {
+    let path = std::env::temp_dir().join("bx-zip-existing.zip");
+    std::fs::write(&path, "old archive").unwrap();
+    let mut cmd = cmd();
+    cmd.args(["zip", "--file", "1"]);
+    cmd.args(["-o", path.to_str().unwrap(), "-t", "access_token"]);
+
+    let create_mock = mock(POST, "/zip_downloads").return_status(202).create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "bx-zip-existing.zip already exists",
+    ));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "old archive");
+    assert_eq!(create_mock.times_called(), 0);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[with_mock_server]
+fn bx_zip_keeps_existing_output_when_archive_fails() {
+    let path = std::env::temp_dir().join("bx-zip-failed.zip");
+    std::fs::write(&path, "old archive").unwrap();
+    let mut cmd = cmd();
+    cmd.args(["zip", "--file", "1", "--overwrite"]);
+    cmd.args(["-o", path.to_str().unwrap(), "-t", "access_token"]);
+
+    let create_mock = mock(POST, "/zip_downloads")
+        .return_status(202)
+        .return_json_body(&json!({
+            "download_url": "http://localhost:5000/zip_downloads/def/content",
+            "status_url": "http://localhost:5000/zip_downloads/def/status"
+        }))
+        .create();
+    let content_mock = mock(GET, "/zip_downloads/def/content")
+        .return_status(200)
+        .return_body("PK trunc")
+        .create();
+    let status_mock = mock(GET, "/zip_downloads/def/status")
+        .return_status(200)
+        .return_json_body(&json!({
+            "total_file_count": 2,
+            "downloaded_file_count": 1,
+            "skipped_file_count": 0,
+            "skipped_folder_count": 0,
+            "state": "failed"
+        }))
+        .create();
+
+    cmd.assert().failure().stderr(predicates::str::contains(
+        "zip download failed after 1 of 2 files",
+    ));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "old archive");
+    assert!(!std::env::temp_dir().join("bx-zip-failed.zip.part").exists());
+    assert_eq!(create_mock.times_called(), 1);
+    assert_eq!(content_mock.times_called(), 1);
+    assert_eq!(status_mock.times_called(), 1);
+    std::fs::remove_file(&path).unwrap();
+}